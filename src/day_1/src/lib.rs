@@ -0,0 +1,115 @@
+use shared::error::ParseError;
+use shared::input::LineGroups;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+pub fn parse_input(aoc_reader: impl Iterator<Item = String>) -> Vec<Vec<usize>> {
+    aoc_reader
+        .paragraphs()
+        .map(|elf_food| {
+            elf_food
+                .iter()
+                .map(|calories| calories.parse::<usize>().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+/// Calorie counts, one per line, with a blank line between elves
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        if !scanner.is_at_end() {
+            scanner.unsigned()?;
+        }
+        scanner.end()
+    })
+}
+
+pub fn part_1(manifests: Vec<Vec<usize>>) -> usize {
+    manifests
+        .iter()
+        .map(|elf_food| elf_food.iter().sum())
+        .max()
+        .unwrap()
+}
+
+struct TopThree {
+    first: usize,
+    second: usize,
+    third: usize,
+}
+
+impl TopThree {
+    pub fn new() -> TopThree {
+        TopThree {
+            first: 0,
+            second: 0,
+            third: 0,
+        }
+    }
+
+    pub fn sum(&self) -> usize {
+        self.first + self.second + self.third
+    }
+
+    pub fn maybe_replace(&mut self, val: usize) {
+        if val >= self.first {
+            self.replace_first(val);
+        } else if val >= self.second {
+            self.replace_second(val);
+        } else if val >= self.third {
+            self.replace_third(val);
+        }
+    }
+
+    fn replace_third(&mut self, val: usize) {
+        self.third = val;
+    }
+
+    fn replace_second(&mut self, val: usize) {
+        self.replace_third(self.second);
+        self.second = val;
+    }
+
+    fn replace_first(&mut self, val: usize) {
+        self.replace_second(self.first);
+        self.first = val;
+    }
+}
+
+pub fn part_2(manifests: Vec<Vec<usize>>) -> usize {
+    let mut top_three = TopThree::new();
+    for manifest in manifests {
+        top_three.maybe_replace(manifest.into_iter().sum::<usize>())
+    }
+    top_three.sum()
+}
+
+#[derive(Default)]
+pub struct Day1;
+
+impl Params for Day1 {}
+
+impl Solution for Day1 {
+    type Input = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input.clone())
+    }
+}
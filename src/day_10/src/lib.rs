@@ -0,0 +1,253 @@
+use std::fmt;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::debug;
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+lazy_static! {
+    static ref ADDX_RE: Regex = Regex::new(r"^addx ([\-0-9]*)$").unwrap();
+    static ref NOOP_RE: Regex = Regex::new(r"^noop$").unwrap();
+    static ref PART_1_MODULUS: usize = 40;
+}
+
+#[derive(Clone)]
+enum InstructionType {
+    Addx,
+    Noop,
+}
+
+#[derive(Clone)]
+pub struct Instruction {
+    instruction_type: InstructionType,
+    val: isize,
+    progress_counter: usize,
+}
+
+impl Instruction {
+    fn new(instruction_type: InstructionType, val: isize) -> Instruction {
+        Instruction {
+            instruction_type,
+            val,
+            progress_counter: 0,
+        }
+    }
+
+    fn execute(&mut self) -> Option<isize> {
+        match self.instruction_type {
+            InstructionType::Noop => Some(0),
+            InstructionType::Addx => {
+                if self.progress_counter == 1 {
+                    Some(self.val)
+                } else {
+                    self.progress_counter += 1;
+                    None
+                }
+            }
+        }
+    }
+}
+
+pub struct Crt {
+    pixels: Vec<Vec<bool>>,
+    ncols: usize,
+}
+
+impl Crt {
+    pub fn new(nrows: usize, ncols: usize) -> Crt {
+        let pixels = (0..nrows)
+            .map(|_| (0..ncols).map(|_| false).collect::<Vec<bool>>())
+            .collect::<Vec<Vec<bool>>>();
+        Crt { pixels, ncols }
+    }
+
+    fn light_pixel(&mut self, row_idx: usize, col_idx: usize) {
+        self.pixels[row_idx][col_idx] = true;
+    }
+
+    /// Render the screen as newline-separated rows of pixels
+    pub fn render(&self) -> String {
+        self.pixels
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| match x {
+                        true => '#',
+                        false => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Draw the screen to the debug log
+    pub fn draw(&self) {
+        debug!("\n{}", self.render());
+    }
+}
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<Instruction> {
+    reader
+        .map(|line| {
+            if let Some(capture) = ADDX_RE.captures(&line) {
+                Instruction::new(
+                    InstructionType::Addx,
+                    capture.get(1).unwrap().as_str().parse::<isize>().unwrap(),
+                )
+            } else if NOOP_RE.captures(&line).is_some() {
+                Instruction::new(InstructionType::Noop, 0)
+            } else {
+                panic!("Could not parse instruction: {}", &line);
+            }
+        })
+        .collect()
+}
+
+/// `noop` and `addx V` instructions
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        if scanner.peek() == Some('n') {
+            scanner.literal("noop")?;
+        } else {
+            scanner.literal("addx ")?;
+            scanner.integer()?;
+        }
+        scanner.end()
+    })
+}
+
+pub fn part_1(instructions: Vec<Instruction>) -> isize {
+    let mut instructions_iter = instructions.into_iter();
+    // the first instruction
+    let mut instruction = instructions_iter.next().unwrap();
+    let mut next_instruction: Option<Instruction>;
+
+    let mut x_register_val: isize = 1;
+    let mut cycle_number: usize = 1;
+    let mut signal_strength_sum: isize = 0;
+    loop {
+        if cycle_number % *PART_1_MODULUS == 20 {
+            signal_strength_sum += cycle_number as isize * x_register_val
+        }
+
+        if let Some(addend) = instruction.execute() {
+            x_register_val += addend;
+            next_instruction = instructions_iter.next();
+            match next_instruction {
+                None => {
+                    break;
+                }
+                Some(x) => {
+                    instruction = x;
+                }
+            }
+        }
+        cycle_number += 1;
+    }
+    signal_strength_sum
+}
+
+pub fn part_2(instructions: Vec<Instruction>, mut crt: Crt) -> Crt {
+    let mut instructions_iter = instructions.into_iter();
+    // the first instruction
+    let mut instruction = instructions_iter.next().unwrap();
+    let mut next_instruction: Option<Instruction>;
+
+    let mut x_register_val: isize = 1;
+    let mut cycle_number: usize = 1;
+    loop {
+        let row_idx: usize = cycle_number / crt.ncols;
+        let col_idx: usize = (cycle_number - 1) % crt.ncols;
+
+        let signed_col_idx: isize = col_idx.try_into().unwrap();
+        if signed_col_idx >= x_register_val - 1 && signed_col_idx <= x_register_val + 1 {
+            crt.light_pixel(row_idx, col_idx);
+        }
+
+        if let Some(addend) = instruction.execute() {
+            x_register_val += addend;
+            next_instruction = instructions_iter.next();
+            match next_instruction {
+                None => {
+                    break;
+                }
+                Some(x) => {
+                    instruction = x;
+                }
+            }
+        }
+        cycle_number += 1;
+    }
+    crt
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Params for Day10 {}
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Answer1 = isize;
+    type Answer2 = Crt;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> isize {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Crt {
+        part_2(input.clone(), Crt::new(6, 40))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(13140).part_2(
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......###.",
+                "#######.......#######.......#######....#",
+            ]
+            .join("\n"),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::example_tests!(Day10);
+
+    #[test]
+    fn test_crt() {
+        let mut crt = Crt::new(2, 3);
+        assert_eq!(crt.render(), "...\n...");
+        crt.light_pixel(1, 2);
+        crt.light_pixel(0, 0);
+        assert_eq!(crt.render(), "#..\n..#");
+        assert_eq!(crt.to_string(), crt.render());
+    }
+}
@@ -0,0 +1,357 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::error::{ParamError, ParseError};
+use shared::examples::Example;
+use shared::input::LineGroups;
+use shared::params::{parse_value, Param, Params};
+use shared::solution::Solution;
+use shared::validate::{check_not_empty, ensure_valid, paragraphs, Diagnostic, Mismatch, Scanner};
+
+lazy_static! {
+    static ref STARTING_ITEMS_RE: Regex = Regex::new(r"Starting items: ([0-9, ]*)$").unwrap();
+    static ref OPERATION_RE: Regex =
+        Regex::new(r"Operation: new = old ([\*\+]) ([0-9]*)$").unwrap();
+    static ref TEST_RE: Regex = Regex::new(r"Test: divisible by ([0-9]*)$").unwrap();
+    static ref TRUE_CASE_RE: Regex = Regex::new(r"If true: throw to monkey ([0-9]*)$").unwrap();
+    static ref FALSE_CASE_RE: Regex = Regex::new(r"If false: throw to monkey ([0-9]*)$").unwrap();
+}
+
+#[derive(Clone)]
+enum OperationType {
+    Addition,
+    Multiplication,
+    Squaring,
+}
+
+#[derive(Clone)]
+struct Operation {
+    operation_type: OperationType,
+    operand: usize,
+}
+
+#[derive(Clone)]
+struct Test {
+    divisor_to_check: usize,
+    true_case_destination: usize,
+    false_case_destination: usize,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<usize>,
+    operation: Operation,
+    test: Test,
+    items_inspected_counter: usize,
+}
+
+fn parse_monkey(lines: &[String]) -> Monkey {
+    // lines[0] is "Monkey i:"
+    let items: VecDeque<usize> = {
+        STARTING_ITEMS_RE
+            .captures(&lines[1])
+            .unwrap()
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(|xx| xx.parse::<usize>().unwrap())
+            .collect()
+    };
+    let operation: Operation = {
+        let line = &lines[2];
+        if let Some(capture) = OPERATION_RE.captures(line) {
+            let operation_type = match capture.get(1).unwrap().as_str() {
+                "*" => OperationType::Multiplication,
+                "+" => OperationType::Addition,
+                _ => panic!("Unknown operation type"),
+            };
+            let operand = capture.get(2).unwrap().as_str().parse::<usize>().unwrap();
+            Operation {
+                operation_type,
+                operand,
+            }
+        } else {
+            assert_eq!(line, "  Operation: new = old * old");
+            Operation {
+                operation_type: OperationType::Squaring,
+                operand: 0,
+            }
+        }
+    };
+    let test: Test = {
+        let divisor_to_check: usize = TEST_RE
+            .captures(&lines[3])
+            .unwrap()
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse::<usize>()
+            .unwrap();
+        let true_case_destination: usize = TRUE_CASE_RE
+            .captures(&lines[4])
+            .unwrap()
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse::<usize>()
+            .unwrap();
+        let false_case_destination: usize = FALSE_CASE_RE
+            .captures(&lines[5])
+            .unwrap()
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse::<usize>()
+            .unwrap();
+        Test {
+            divisor_to_check,
+            true_case_destination,
+            false_case_destination,
+        }
+    };
+    Monkey {
+        items,
+        operation,
+        test,
+        items_inspected_counter: 0,
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<Monkey> {
+    reader
+        .paragraphs()
+        .map(|lines| parse_monkey(&lines))
+        .collect()
+}
+
+fn check_monkey_line(idx: usize, scanner: &mut Scanner) -> Result<(), Mismatch> {
+    match idx {
+        0 => {
+            scanner.literal("Monkey ")?;
+            scanner.unsigned()?;
+            scanner.literal(":")?;
+        }
+        1 => {
+            scanner.literal("  Starting items: ")?;
+            scanner.unsigned()?;
+            while scanner.optional(", ") {
+                scanner.unsigned()?;
+            }
+        }
+        2 => {
+            scanner.literal("  Operation: new = old ")?;
+            if scanner.one_of("*+")? == '*' && scanner.optional(" old") {
+                return scanner.end();
+            }
+            scanner.literal(" ")?;
+            scanner.unsigned()?;
+        }
+        3 => {
+            scanner.literal("  Test: divisible by ")?;
+            scanner.unsigned()?;
+        }
+        4 => {
+            scanner.literal("    If true: throw to monkey ")?;
+            scanner.unsigned()?;
+        }
+        _ => {
+            scanner.literal("    If false: throw to monkey ")?;
+            scanner.unsigned()?;
+        }
+    }
+    scanner.end()
+}
+
+/// Blocks of six lines describing each monkey, separated by blank lines
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_not_empty(input);
+    for monkey in paragraphs(input) {
+        for (idx, (line_no, line)) in monkey.iter().enumerate().take(6) {
+            if let Err(mismatch) = check_monkey_line(idx, &mut Scanner::new(line)) {
+                diagnostics.push(mismatch.at_line(*line_no, line));
+            }
+        }
+        let (last_line_no, _) = monkey[monkey.len() - 1];
+        if monkey.len() != 6 {
+            let line_no = monkey
+                .get(6)
+                .map_or(last_line_no + 1, |(line_no, _)| *line_no);
+            diagnostics.push(Diagnostic::new(
+                line_no,
+                1,
+                "six lines per monkey, followed by a blank line",
+                monkey.get(6).map_or("", |(_, line)| line),
+            ));
+        }
+    }
+    diagnostics
+}
+
+fn _monkey_around_part_1(monkeys: &mut [Monkey]) {
+    for src_idx in 0..monkeys.len() {
+        let mut dest_indx: usize;
+        while let Some(item) = monkeys[src_idx].items.pop_front() {
+            monkeys[src_idx].items_inspected_counter += 1;
+            let item_to_toss: usize = match monkeys[src_idx].operation.operation_type {
+                OperationType::Addition => (item + monkeys[src_idx].operation.operand) / 3,
+                OperationType::Multiplication => item * monkeys[src_idx].operation.operand / 3,
+                OperationType::Squaring => item * item / 3,
+            };
+            if item_to_toss.is_multiple_of(monkeys[src_idx].test.divisor_to_check) {
+                dest_indx = monkeys[src_idx].test.true_case_destination;
+            } else {
+                dest_indx = monkeys[src_idx].test.false_case_destination;
+            }
+            monkeys[dest_indx].items.push_back(item_to_toss);
+        }
+    }
+}
+
+/// By capping things at _a_ common multiple of all of the monkeys test
+/// divisors, we can preserve the test behavior.
+fn _monkey_around_part_2(monkeys: &mut [Monkey], common_multiple: usize) {
+    for src_idx in 0..monkeys.len() {
+        let mut dest_indx: usize;
+        while let Some(item) = monkeys[src_idx].items.pop_front() {
+            monkeys[src_idx].items_inspected_counter += 1;
+            let item_to_toss: usize = match monkeys[src_idx].operation.operation_type {
+                OperationType::Addition => item + monkeys[src_idx].operation.operand,
+                OperationType::Multiplication => item * monkeys[src_idx].operation.operand,
+                OperationType::Squaring => item * item,
+            } % common_multiple;
+            if item_to_toss.is_multiple_of(monkeys[src_idx].test.divisor_to_check) {
+                dest_indx = monkeys[src_idx].test.true_case_destination;
+            } else {
+                dest_indx = monkeys[src_idx].test.false_case_destination;
+            }
+            monkeys[dest_indx].items.push_back(item_to_toss);
+        }
+    }
+}
+
+pub fn part_1(mut monkeys: Vec<Monkey>, n_rounds: usize) -> usize {
+    for _ in 0..n_rounds {
+        _monkey_around_part_1(&mut monkeys);
+    }
+    monkeys.sort_by_key(|m| Reverse(m.items_inspected_counter));
+    monkeys[0].items_inspected_counter * monkeys[1].items_inspected_counter
+}
+
+pub fn part_2(mut monkeys: Vec<Monkey>, n_rounds: usize) -> usize {
+    let common_multiple = monkeys.iter().map(|m| m.test.divisor_to_check).product();
+    for _ in 0..n_rounds {
+        _monkey_around_part_2(&mut monkeys, common_multiple);
+    }
+    monkeys.sort_by_key(|m| Reverse(m.items_inspected_counter));
+    monkeys[0].items_inspected_counter * monkeys[1].items_inspected_counter
+}
+
+pub struct Day11 {
+    pub rounds_1: usize,
+    pub rounds_2: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Day11 {
+        Day11 {
+            rounds_1: 20,
+            rounds_2: 10_000,
+        }
+    }
+}
+
+impl Params for Day11 {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "rounds-1",
+                self.rounds_1,
+                "rounds of monkey business in part 1",
+            ),
+            Param::new(
+                "rounds-2",
+                self.rounds_2,
+                "rounds of monkey business in part 2",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "rounds-1" => self.rounds_1 = parse_value(name, value)?,
+            "rounds-2" => self.rounds_2 = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input.clone(), self.rounds_1)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input.clone(), self.rounds_2)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt")
+            .part_1(10605)
+            .part_2(2713310158u64)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day11);
+
+    #[test]
+    fn test_parse_input() {
+        let _monkeys = parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        let _monkeys = parse_input(
+            AocBufReader::from_path("inputs/part_1.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        assert_eq!(validate(&input), vec![]);
+
+        let input = input.replacen("new = old * 19", "new = old ^ 19", 1);
+        let diagnostics = validate(&input);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (3, 24));
+        assert_eq!(diagnostics[0].expected, "one of '*+'");
+
+        let error = Day11::default().parse(&input).err().unwrap();
+        assert_eq!(error.to_string(), "line 3: column 24: expected one of '*+'");
+    }
+}
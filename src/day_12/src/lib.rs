@@ -0,0 +1,168 @@
+use shared::conversion::char_to_usize;
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::geom::Point2;
+use shared::grid::Grid;
+use shared::params::Params;
+use shared::search::bfs;
+use shared::solution::Solution;
+use shared::validate::{check_grid, check_not_empty, ensure_valid, numbered_lines, Diagnostic};
+
+type Coord = Point2<usize>;
+
+pub struct Map {
+    start: Coord,
+    end: Coord,
+    heights: Grid<usize>,
+}
+
+impl Map {
+    fn next_coords_allowed(&self, coord: &Coord) -> Vec<Coord> {
+        let current_val = self.heights[*coord];
+        self.heights
+            .neighbors_4(*coord)
+            .filter(|neighbor| self.heights[*neighbor] <= current_val + 1)
+            .collect()
+    }
+
+    /// The number of steps to the end from the closest of `starts`, if the
+    /// end can be reached at all
+    fn find_route(&self, starts: impl IntoIterator<Item = Coord>) -> Option<usize> {
+        bfs(
+            starts,
+            |coord| self.next_coords_allowed(coord),
+            |coord| *coord == self.end,
+        )
+        .map(|path| path.cost)
+    }
+}
+
+fn parse_map(lines: impl Iterator<Item = String>) -> Result<Map, ParseError> {
+    let chars = Grid::parse(lines, Some)?;
+    let start = chars
+        .position(|c| *c == 'S')
+        .ok_or(ParseError::new("no start position 'S'"))?;
+    let end = chars
+        .position(|c| *c == 'E')
+        .ok_or(ParseError::new("no end position 'E'"))?;
+    let heights = chars.map(|c| match c {
+        'S' => char_to_usize('a'),
+        'E' => char_to_usize('z'),
+        _ => char_to_usize(*c),
+    });
+
+    Ok(Map {
+        start,
+        end,
+        heights,
+    })
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Map {
+    parse_map(reader).unwrap()
+}
+
+/// A rectangle of heights from `a` to `z`, with one start `S` and one end `E`
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let mut diagnostics = check_not_empty(input);
+    diagnostics.extend(check_grid(&lines, "abcdefghijklmnopqrstuvwxyzSE"));
+    for marker in ['S', 'E'] {
+        let n_markers = input.chars().filter(|c| *c == marker).count();
+        if n_markers != 1 {
+            diagnostics.push(Diagnostic::new(
+                1,
+                1,
+                format!("exactly one '{}', found {}", marker, n_markers),
+                lines.first().map_or("", |(_, line)| line),
+            ));
+        }
+    }
+    if diagnostics.is_empty() {
+        diagnostics.extend(check_route(&lines));
+    }
+    diagnostics
+}
+
+/// The end can be reached from the start, and so from every lowest square
+/// that part 2 might set off from too
+fn check_route(lines: &[(usize, &str)]) -> Vec<Diagnostic> {
+    let map = parse_input(lines.iter().map(|(_, line)| line.to_string()));
+    match map.find_route([map.start]) {
+        Some(_) => Vec::new(),
+        None => {
+            let (line_no, line) = lines[map.end.y];
+            vec![Diagnostic::new(
+                line_no,
+                map.end.x + 1,
+                "an end that can be climbed to from 'S'",
+                line,
+            )]
+        }
+    }
+}
+
+pub fn part_1(map: &Map) -> usize {
+    map.find_route([map.start])
+        .expect("validate checks for a route")
+}
+
+pub fn part_2(map: &Map) -> usize {
+    let lowest_height = char_to_usize('a');
+    map.find_route(
+        map.heights
+            .iter()
+            .filter(|(_, height)| **height == lowest_height)
+            .map(|(coord, _)| coord),
+    )
+    .expect("validate checks for a route")
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Params for Day12 {}
+
+impl Solution for Day12 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        parse_map(input.lines().map(String::from))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(31).part_2(29)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::example_tests!(Day12);
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("SbcdefghijklmnopqrstuvwxyE\n"), vec![]);
+        // nowhere to climb to the end from x
+        let input = "SbcdefghijklmnopqrstuvwxxE\n";
+        let diagnostics = validate(input);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (1, 26));
+        assert!(Day12.parse(input).is_err());
+    }
+}
@@ -0,0 +1,496 @@
+use std::cmp;
+
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::input::LineGroups;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_not_empty, ensure_valid, paragraphs, Diagnostic, Mismatch, Scanner};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum ObjectType {
+    List,
+    Element,
+}
+
+#[derive(Debug, Clone)]
+struct Object {
+    object_type: ObjectType,
+    element_value: usize,
+    list: Vec<Object>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Comparison {
+    Ordered,
+    Equal,
+    OutOfOrder,
+}
+
+impl Object {
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn as_string(&self) -> String {
+        if self.object_type == ObjectType::Element {
+            self.element_value.to_string()
+        } else {
+            format!(
+                "[{}]",
+                self.list
+                    .iter()
+                    .map(Object::as_string)
+                    .collect::<Vec<String>>()
+                    .join(",")
+            )
+        }
+    }
+
+    fn new_val(element_value: usize) -> Object {
+        Object {
+            object_type: ObjectType::Element,
+            element_value,
+            list: vec![],
+        }
+    }
+
+    fn empty_list() -> Object {
+        Object {
+            object_type: ObjectType::List,
+            element_value: 0,
+            list: vec![],
+        }
+    }
+
+    fn list_from_val(element_value: usize) -> Object {
+        Object {
+            object_type: ObjectType::List,
+            element_value,
+            list: vec![Object::new_val(element_value)],
+        }
+    }
+
+    fn is_ordered(left: &Object, right: &Object) -> Comparison {
+        if left.object_type == ObjectType::List && right.object_type == ObjectType::List {
+            for idx in 0..cmp::min(left.len(), right.len()) {
+                match Object::is_ordered(&left.list[idx], &right.list[idx]) {
+                    Comparison::Ordered => return Comparison::Ordered,
+                    Comparison::Equal => (),
+                    Comparison::OutOfOrder => return Comparison::OutOfOrder,
+                }
+            }
+            if left.len() < right.len() {
+                Comparison::Ordered
+            } else if left.len() > right.len() {
+                Comparison::OutOfOrder
+            } else {
+                Comparison::Equal
+            }
+        } else if left.object_type == ObjectType::Element
+            && right.object_type == ObjectType::Element
+        {
+            if left.element_value < right.element_value {
+                Comparison::Ordered
+            } else if left.element_value > right.element_value {
+                Comparison::OutOfOrder
+            } else {
+                Comparison::Equal
+            }
+        } else {
+            if left.object_type == ObjectType::Element {
+                Object::is_ordered(&Object::list_from_val(left.element_value), right)
+            } else {
+                Object::is_ordered(left, &Object::list_from_val(right.element_value))
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PacketPair {
+    left: Object,
+    right: Object,
+}
+
+impl PacketPair {
+    fn is_ordered(&self) -> bool {
+        match Object::is_ordered(&self.left, &self.right) {
+            Comparison::Ordered => true,
+            Comparison::OutOfOrder => false,
+            Comparison::Equal => unreachable!("validate rules out equal packets"),
+        }
+    }
+}
+
+/// start_idx is the index of an opening square bracket.
+/// Finds the index of the corresponding closing square bracket.
+fn _get_end_idx(packet_str: &str, mut start_idx: usize) -> usize {
+    let mut n_left_brackets: usize = 1;
+    let mut n_right_brackets: usize = 0;
+    loop {
+        start_idx += 1;
+        match packet_str.chars().nth(start_idx).unwrap() {
+            '[' => n_left_brackets += 1,
+            ']' => n_right_brackets += 1,
+            _ => (),
+        }
+        if n_left_brackets == n_right_brackets {
+            break;
+        }
+    }
+    start_idx
+}
+
+fn parse_packet(packet: String) -> Object {
+    if packet == "[]" {
+        return Object {
+            object_type: ObjectType::List,
+            element_value: 0,
+            list: vec![],
+        };
+    }
+
+    let mut list: Vec<Object> = Vec::new();
+    let mut current_number: String = "".to_string();
+
+    // the first character is alwas '['
+    let mut cursor_idx = 1;
+    loop {
+        let c: char = packet.chars().nth(cursor_idx).unwrap();
+        if c.is_ascii_digit() {
+            current_number.push(c);
+        } else if c == ',' {
+            list.push(Object {
+                object_type: ObjectType::Element,
+                element_value: current_number.parse::<usize>().unwrap(),
+                list: vec![],
+            });
+            current_number = "".to_string();
+        } else if c == '[' {
+            let end_idx: usize = _get_end_idx(&packet, cursor_idx);
+            if end_idx == cursor_idx + 1 {
+                list.push(Object::empty_list());
+            } else {
+                // recurse!
+                list.push(parse_packet(packet[cursor_idx..end_idx + 1].to_string()));
+            }
+            cursor_idx = end_idx + 1;
+        } else {
+            panic!("unexpected character {}", c);
+        }
+        cursor_idx += 1;
+        if cursor_idx >= packet.len() - 1 {
+            break;
+        }
+    }
+    if !current_number.is_empty() {
+        list.push(Object {
+            object_type: ObjectType::Element,
+            element_value: current_number.parse::<usize>().unwrap(),
+            list: vec![],
+        });
+    }
+    Object {
+        object_type: ObjectType::List,
+        element_value: 0,
+        list,
+    }
+}
+
+fn parse_packet_pair(packet_1: String, packet_2: String) -> PacketPair {
+    PacketPair {
+        left: parse_packet(packet_1),
+        right: parse_packet(packet_2),
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<PacketPair> {
+    reader
+        .paragraphs()
+        .map(|paragraph| {
+            let [line_1, line_2]: [String; 2] = paragraph.try_into().unwrap();
+            parse_packet_pair(line_1, line_2)
+        })
+        .collect()
+}
+
+/// A list in brackets of numbers and further lists, separated by commas
+fn check_packet(scanner: &mut Scanner) -> Result<(), Mismatch> {
+    scanner.literal("[")?;
+    if scanner.optional("]") {
+        return Ok(());
+    }
+    loop {
+        if scanner.peek() == Some('[') {
+            check_packet(scanner)?;
+        } else {
+            scanner.unsigned()?;
+        }
+        if scanner.optional("]") {
+            return Ok(());
+        }
+        if !scanner.optional(",") {
+            return Err(scanner.mismatch("',' or ']'"));
+        }
+    }
+}
+
+/// Pairs of packets, separated by blank lines
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_not_empty(input);
+    for pair in paragraphs(input) {
+        for (line_no, line) in pair.iter() {
+            let mut scanner = Scanner::new(line);
+            if let Err(mismatch) = check_packet(&mut scanner).and_then(|_| scanner.end()) {
+                diagnostics.push(mismatch.at_line(*line_no, line));
+            }
+        }
+        if pair.len() != 2 {
+            let (line_no, line) = pair.get(2).unwrap_or(&pair[0]);
+            diagnostics.push(Diagnostic::new(
+                *line_no,
+                1,
+                "packets in pairs separated by blank lines",
+                *line,
+            ));
+        }
+    }
+    if diagnostics.is_empty() {
+        diagnostics.extend(check_distinct(input));
+    }
+    diagnostics
+}
+
+/// No two packets, dividers included, compare equal, since neither order
+/// would be right
+fn check_distinct(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut seen: Vec<(String, Object)> = ["[[2]]", "[[6]]"]
+        .into_iter()
+        .map(|divider| {
+            (
+                format!("the divider {}", divider),
+                parse_packet(divider.to_string()),
+            )
+        })
+        .collect();
+    for (line_no, line) in paragraphs(input).into_iter().flatten() {
+        let packet = parse_packet(line.to_string());
+        let equal = seen
+            .iter()
+            .find(|(_, other)| Object::is_ordered(&packet, other) == Comparison::Equal);
+        if let Some((name, _)) = equal {
+            let expected = format!("a packet that differs from {}", name);
+            diagnostics.push(Diagnostic::new(line_no, 1, expected, line));
+        }
+        seen.push((format!("line {}", line_no), packet));
+    }
+    diagnostics
+}
+
+pub fn part_1(packet_pairs: &[PacketPair]) -> usize {
+    packet_pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, packet_pair)| packet_pair.is_ordered())
+        .map(|(idx, _)| idx + 1)
+        .sum()
+}
+
+fn bubble_sort(packets: &mut [Object]) {
+    let mut swap_occurred = true;
+    while swap_occurred {
+        swap_occurred = false;
+        for idx in 0..(packets.len() - 1) {
+            let is_ordered: bool = match Object::is_ordered(&packets[idx], &packets[idx + 1]) {
+                Comparison::Ordered => true,
+                Comparison::OutOfOrder => false,
+                Comparison::Equal => unreachable!("validate rules out equal packets"),
+            };
+
+            if !is_ordered {
+                unsafe {
+                    let pa: *mut Object = &mut packets[idx];
+                    let pb: *mut Object = &mut packets[idx + 1];
+                    std::ptr::swap(pa, pb);
+                }
+                swap_occurred = true;
+            }
+        }
+    }
+}
+
+pub fn part_2(packet_pairs: Vec<PacketPair>) -> usize {
+    let divider_packet_string_1 = "[[2]]".to_string();
+    let divider_packet_string_2 = "[[6]]".to_string();
+
+    let mut packets: Vec<Object> = packet_pairs
+        .into_iter()
+        .flat_map(|packet_pair| [packet_pair.left, packet_pair.right])
+        .collect();
+    packets.push(parse_packet(divider_packet_string_1.clone()));
+    packets.push(parse_packet(divider_packet_string_2.clone()));
+    bubble_sort(&mut packets);
+
+    let mut packet_1_idx: usize = 0;
+    let mut packet_2_idx: usize = 0;
+    for (idx, packet) in packets.iter().enumerate() {
+        if packet.as_string() == divider_packet_string_1 {
+            packet_1_idx = idx + 1;
+        }
+        if packet.as_string() == divider_packet_string_2 {
+            packet_2_idx = idx + 1;
+        }
+    }
+
+    packet_1_idx * packet_2_idx
+}
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Params for Day13 {}
+
+impl Solution for Day13 {
+    type Input = Vec<PacketPair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(13).part_2(140)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::example_tests!(Day13);
+
+    #[test]
+    fn test_parse_packet() {
+        let empty_packet = parse_packet("[]".to_string());
+        assert_eq!(empty_packet.object_type, ObjectType::List);
+        assert_eq!(empty_packet.list.len(), 0);
+
+        parse_packet("[1,[2,[3,[4,[5,6,7]]]],8,9]".to_string());
+    }
+
+    #[test]
+    fn test_as_string() {
+        let packet = parse_packet("[[1],[2,3,4]]".to_string());
+        assert_eq!(packet.as_string(), "[[1],[2,3,4]]".to_string());
+    }
+
+    #[test]
+    fn test_is_ordered() {
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[1,1,3,1,1]".to_string()),
+                &parse_packet("[1,1,5,1,1]".to_string())
+            ),
+            Comparison::Ordered
+        );
+
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[[1],[2,3,4]]".to_string()),
+                &parse_packet("[[1],4]".to_string())
+            ),
+            Comparison::Ordered
+        );
+
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[9]".to_string()),
+                &parse_packet("[[8,7,6]]".to_string())
+            ),
+            Comparison::OutOfOrder
+        );
+
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[[4,4],4,4]".to_string()),
+                &parse_packet("[[4,4],4,4,4]".to_string())
+            ),
+            Comparison::Ordered
+        );
+
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[]".to_string()),
+                &parse_packet("[3]".to_string())
+            ),
+            Comparison::Ordered
+        );
+
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[[[]]]".to_string()),
+                &parse_packet("[[]]".to_string())
+            ),
+            Comparison::OutOfOrder
+        );
+
+        assert_eq!(
+            Object::is_ordered(
+                &parse_packet("[1,[2,[3,[4,[5,6,7]]]],8,9]".to_string()),
+                &parse_packet("[1,[2,[3,[4,[5,6,0]]]],8,9]".to_string())
+            ),
+            Comparison::OutOfOrder
+        );
+    }
+
+    #[test]
+    fn test_parse_bug_case() {
+        let s = "[[],[],[[2],[[],[7,10,0],2],[[6],3]],[8,10,[4]],[2,0,2,[2,[2,0],4,[1,2,7,4]],7]]"
+            .to_string();
+        assert_eq!(parse_packet(s.clone()).as_string(), s);
+    }
+
+    #[test]
+    fn test_multi_digit() {
+        Object::is_ordered(
+            &parse_packet(
+                "[[],[],[[2],[[],[7,10,0],2],[[6],3]],[8,10,[4]],[2,0,2,[2,[2,0],4,[1,2,7,4]],7]]"
+                    .to_string(),
+            ),
+            &parse_packet(
+                "[[4,[[5,10,9,10,8],[0,9,0,8],4,1,10],[[0,3],[3,5],10]],[8],[],[7]]".to_string(),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("[1,[2,[]]]\n[[1],4]\n\n[]\n[3]\n"), vec![]);
+        assert_eq!(
+            validate("[1,[2,[]]]\n[[1] 4]\n"),
+            vec![Diagnostic::new(2, 5, "',' or ']'", "[[1] 4]")]
+        );
+        assert_eq!(validate("[1]\n[2]\n[3]\n")[0].line, 3);
+
+        let diagnostics = validate("[1]\n[3]\n\n[[1]]\n[4]\n");
+        assert_eq!(diagnostics[0].line, 4);
+        assert_eq!(diagnostics[0].expected, "a packet that differs from line 1");
+        assert!(Day13.parse("[[2]]\n[3]\n").is_err());
+    }
+}
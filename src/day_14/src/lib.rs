@@ -0,0 +1,386 @@
+use std::cmp;
+use std::collections::HashSet;
+
+use shared::animate::{Animated, Colour, Glyph, Render, Scene};
+use shared::error::{ParamError, ParseError};
+use shared::examples::Example;
+use shared::geom::Point2;
+use shared::grid::SparseGrid;
+use shared::params::{parse_value, Param, Params};
+use shared::sim::{Driver, Simulation};
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+pub type Coord = Point2<isize>;
+
+/// Where a unit of sand tries to fall next, in order of preference
+fn fall_candidates(coord: &Coord) -> [Coord; 3] {
+    [
+        Coord::new(coord.x, coord.y + 1),
+        Coord::new(coord.x - 1, coord.y + 1),
+        Coord::new(coord.x + 1, coord.y + 1),
+    ]
+}
+
+#[derive(Clone)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    tiles: SparseGrid<Tile>,
+    max_rock_depth: isize,
+    sand_source: Coord,
+    /// Whether there is a floor two below the deepest rock, rather than an abyss
+    has_floor: bool,
+    /// Whether sand has stopped coming to rest
+    is_full: bool,
+    n_sand_tiles: usize,
+}
+
+impl Cave {
+    /// Start pouring sand in at `sand_source`
+    fn pouring(self, sand_source: Coord, has_floor: bool) -> Cave {
+        Cave {
+            sand_source,
+            has_floor,
+            ..self
+        }
+    }
+
+    fn add_sand(&mut self, sand_source: &Coord) -> bool {
+        let mut sand_tile_location: Coord = *sand_source;
+        loop {
+            match fall_candidates(&sand_tile_location)
+                .into_iter()
+                .find(|candidate| !self.tiles.contains(candidate))
+            {
+                Some(next_location) => sand_tile_location = next_location,
+                None => {
+                    // sand is at rest, cave is not yet full
+                    self.tiles.insert(sand_tile_location, Tile::Sand);
+                    return false;
+                }
+            }
+            if sand_tile_location.y >= self.max_rock_depth {
+                return true;
+            }
+        }
+    }
+
+    fn is_floor(&self, coord: &Coord) -> bool {
+        coord.y == self.max_rock_depth + 2
+    }
+
+    fn add_sand_part_2(&mut self, sand_source: &Coord) -> bool {
+        let mut sand_tile_location: Coord = *sand_source;
+        loop {
+            match fall_candidates(&sand_tile_location)
+                .into_iter()
+                .find(|candidate| !self.tiles.contains(candidate) && !self.is_floor(candidate))
+            {
+                Some(next_location) => sand_tile_location = next_location,
+                None => {
+                    // sand is at rest
+                    self.tiles.insert(sand_tile_location, Tile::Sand);
+                    return sand_tile_location.y == sand_source.y;
+                }
+            }
+        }
+    }
+
+    /// Draw the rocks and the sand that has come to rest
+    pub fn render(&self) -> String {
+        self.tiles.render('.', |tile| match tile {
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        })
+    }
+}
+
+/// What `aoc animate` draws in a cave
+pub enum CaveCell {
+    Rock,
+    Sand,
+    Source,
+    Floor,
+}
+
+impl Render for Cave {
+    type Cell = CaveCell;
+
+    fn cells(&self) -> Vec<(Coord, CaveCell)> {
+        // sand that comes to rest at the source is drawn over it
+        let mut cells: Vec<(Coord, CaveCell)> = vec![(self.sand_source, CaveCell::Source)];
+        cells.extend(self.tiles.iter().map(|(coord, tile)| match tile {
+            Tile::Rock => (*coord, CaveCell::Rock),
+            Tile::Sand => (*coord, CaveCell::Sand),
+        }));
+        if let (true, Some((min, max))) = (self.has_floor, self.tiles.bounds()) {
+            let row = self.max_rock_depth + 2;
+            cells
+                .extend((min.x - 1..=max.x + 1).map(|col| (Coord::new(col, row), CaveCell::Floor)));
+        }
+        cells
+    }
+}
+
+fn cave_glyph(cell: &CaveCell) -> Glyph {
+    match cell {
+        CaveCell::Rock => Glyph::coloured('#', Colour::Grey),
+        CaveCell::Sand => Glyph::coloured('o', Colour::Yellow),
+        CaveCell::Source => Glyph::coloured('+', Colour::Red),
+        CaveCell::Floor => Glyph::coloured('#', Colour::White),
+    }
+}
+
+/// Each step pours in one unit of sand
+impl Simulation for Cave {
+    type Snapshot = Cave;
+
+    fn step(&mut self) -> bool {
+        let sand_source = self.sand_source;
+        if self.has_floor {
+            // the unit that blocks the source still comes to rest
+            self.is_full = self.add_sand_part_2(&sand_source);
+            self.n_sand_tiles += 1;
+            true
+        } else {
+            self.is_full = self.add_sand(&sand_source);
+            if !self.is_full {
+                self.n_sand_tiles += 1;
+            }
+            !self.is_full
+        }
+    }
+
+    fn snapshot(&self) -> Cave {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Cave) {
+        *self = snapshot.clone();
+    }
+
+    fn is_done(&self) -> bool {
+        self.is_full
+    }
+}
+
+fn _line_to_coords(line: String) -> Vec<Coord> {
+    line.split(" -> ")
+        .map(|str| {
+            let mut x_y = str.split(",");
+            let x = x_y.next().unwrap().parse::<isize>().unwrap();
+            let y = x_y.next().unwrap().parse::<isize>().unwrap();
+            Coord { x, y }
+        })
+        .collect()
+}
+
+fn _interpolate(start: &Coord, end: &Coord) -> HashSet<Coord> {
+    if start.y == end.y {
+        (cmp::min(start.x, end.x)..=cmp::max(start.x, end.x))
+            .map(|x| Coord { x, y: start.y })
+            .collect::<HashSet<Coord>>()
+    } else if start.x == end.x {
+        (cmp::min(start.y, end.y)..=cmp::max(start.y, end.y))
+            .map(|y| Coord { x: start.x, y })
+            .collect::<HashSet<Coord>>()
+    } else {
+        panic!("WE DON'T DO DIAGONALS!");
+    }
+}
+
+fn parse_line(line: String) -> HashSet<Coord> {
+    let mut rock_coords: HashSet<Coord> = HashSet::new();
+    let vertex_coords = _line_to_coords(line);
+    for idx in 0..(vertex_coords.len() - 1) {
+        rock_coords.extend(_interpolate(&vertex_coords[idx], &vertex_coords[idx + 1]));
+    }
+
+    rock_coords
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Cave {
+    let mut rock_coords: HashSet<Coord> = HashSet::new();
+    for line in reader {
+        rock_coords.extend(parse_line(line));
+    }
+    let max_rock_depth = rock_coords.iter().map(|coord| coord.y).max().unwrap();
+
+    Cave {
+        tiles: rock_coords
+            .into_iter()
+            .map(|coord| (coord, Tile::Rock))
+            .collect(),
+        max_rock_depth,
+        sand_source: Coord::new(500, 0),
+        has_floor: false,
+        is_full: false,
+        n_sand_tiles: 0,
+    }
+}
+
+/// Rock paths of `x,y` points joined by ` -> `
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        loop {
+            scanner.unsigned()?;
+            scanner.literal(",")?;
+            scanner.unsigned()?;
+            if !scanner.optional(" -> ") {
+                break;
+            }
+        }
+        scanner.end()
+    })
+}
+
+fn pour_sand(cave: Cave, sand_source: Coord, has_floor: bool) -> usize {
+    let mut driver = Driver::new(cave.pouring(sand_source, has_floor));
+    driver.run_until_done();
+    driver.sim().n_sand_tiles
+}
+
+pub fn part_1(cave: Cave, sand_source: Coord) -> usize {
+    pour_sand(cave, sand_source, false)
+}
+
+pub fn part_2(cave: Cave, sand_source: Coord) -> usize {
+    pour_sand(cave, sand_source, true)
+}
+
+pub struct Day14 {
+    /// Where the sand pours in from
+    pub sand_source: Coord,
+}
+
+impl Default for Day14 {
+    fn default() -> Day14 {
+        Day14 {
+            sand_source: Coord::new(500, 0),
+        }
+    }
+}
+
+impl Params for Day14 {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "source-x",
+                self.sand_source.x,
+                "column the sand pours in at",
+            ),
+            Param::new("source-y", self.sand_source.y, "depth the sand pours in at"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "source-x" => self.sand_source.x = parse_value(name, value)?,
+            "source-y" => self.sand_source.y = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input.clone(), self.sand_source)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input.clone(), self.sand_source)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(24).part_2(93)]
+    }
+
+    fn animation(&self, input: &Self::Input, part: usize) -> Option<Box<dyn Scene>> {
+        let cave = input.clone().pouring(self.sand_source, part == 2);
+        Some(Box::new(Animated::new(cave, cave_glyph)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day14);
+
+    #[test]
+    fn test_parse_line() {
+        parse_line("498,4 -> 498,6 -> 496,6".to_string());
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let cave = parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        let mut driver = Driver::recording(cave.pouring(Coord::new(500, 0), false));
+        // the last unit falls into the abyss
+        assert_eq!(driver.run_until_done(), 25);
+        assert_eq!(driver.sim().n_sand_tiles, 24);
+        assert_eq!(driver.history()[2].n_sand_tiles, 2);
+        let cave = driver.into_sim();
+        assert_eq!(
+            cave.render(),
+            [
+                "......o...",
+                ".....ooo..",
+                "....#ooo##",
+                "...o#ooo#.",
+                "..###ooo#.",
+                "....oooo#.",
+                ".o.ooooo#.",
+                "#########.",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_animation() {
+        let day = Day14::default();
+        let cave = day
+            .parse(&std::fs::read_to_string("inputs/example.txt").unwrap())
+            .unwrap();
+        let mut scene = day.animation(&cave, 2).unwrap();
+        assert!(scene
+            .glyphs()
+            .contains(&(Coord::new(500, 0), Glyph::coloured('+', Colour::Red))));
+
+        while scene.step() {}
+        assert_eq!(scene.n_steps(), 93);
+        assert!(scene
+            .glyphs()
+            .contains(&(Coord::new(500, 0), Glyph::coloured('o', Colour::Yellow))));
+        // the floor reaches a little past the sand on either side
+        assert_eq!(
+            scene.focus(),
+            Some((Coord::new(489, 0), Coord::new(511, 11)))
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use std::ops::Range;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::error::{ParamError, ParseError};
+use shared::examples::Example;
+use shared::generate::Rng;
+use shared::geom::Point2;
+use shared::interval::{Interval, IntervalSet};
+use shared::params::{parse_value, Param, Params};
+use shared::pool::Pool;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+lazy_static! {
+    static ref INPUT_RE: Regex = Regex::new(
+        r"^Sensor at x=([0-9\-]*), y=([0-9\-]*): closest beacon is at x=([0-9\-]*), y=([0-9\-]*)$"
+    )
+    .unwrap();
+}
+
+type Coord = Point2<isize>;
+
+pub struct Sensor {
+    location: Coord,
+    closest_beacon: Coord,
+    distance_to_beacon: isize,
+}
+
+impl Sensor {
+    /// The columns in `row` that are no further from the sensor than its beacon
+    fn interval_within_x_at_row(&self, row: isize) -> Option<Interval<isize>> {
+        let distance_to_row = (row - self.location.y).abs();
+        if distance_to_row > self.distance_to_beacon {
+            None
+        } else {
+            let horizontal_remainder = self.distance_to_beacon - distance_to_row;
+            Some(Interval::new(
+                self.location.x - horizontal_remainder,
+                self.location.x + horizontal_remainder,
+            ))
+        }
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<Sensor> {
+    reader
+        .map(|line| {
+            let captures = INPUT_RE.captures(&line).unwrap();
+            let location = Coord::new(
+                captures.get(1).unwrap().as_str().parse::<isize>().unwrap(),
+                captures.get(2).unwrap().as_str().parse::<isize>().unwrap(),
+            );
+            let closest_beacon = Coord::new(
+                captures.get(3).unwrap().as_str().parse::<isize>().unwrap(),
+                captures.get(4).unwrap().as_str().parse::<isize>().unwrap(),
+            );
+            let distance_to_beacon = location.manhattan_distance(&closest_beacon);
+            Sensor {
+                location,
+                closest_beacon,
+                distance_to_beacon,
+            }
+        })
+        .collect()
+}
+
+/// One sensor per line, with the position of its closest beacon
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        scanner.literal("Sensor at x=")?;
+        scanner.integer()?;
+        scanner.literal(", y=")?;
+        scanner.integer()?;
+        scanner.literal(": closest beacon is at x=")?;
+        scanner.integer()?;
+        scanner.literal(", y=")?;
+        scanner.integer()?;
+        scanner.end()
+    })
+}
+
+/// `n_sensors` sensors spread over `0..=bound` in both directions, each with
+/// a beacon close enough that together they cover most of the area
+pub fn generate(rng: &mut Rng, n_sensors: usize, bound: isize) -> String {
+    let bound = bound.max(1) as i64;
+    let reach = (2.0 * bound as f64 / (n_sensors.max(1) as f64).sqrt()).max(1.0) as i64;
+    (0..n_sensors)
+        .map(|_| {
+            let (x, y) = (rng.range(0..=bound), rng.range(0..=bound));
+            let distance = rng.range(1..=reach);
+            let dx = rng.range(-distance..=distance);
+            let dy = if rng.chance(0.5) {
+                distance - dx.abs()
+            } else {
+                dx.abs() - distance
+            };
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x,
+                y,
+                x + dx,
+                y + dy
+            )
+        })
+        .collect()
+}
+
+pub fn part_1(sensors: &[Sensor], row: isize) -> usize {
+    let mut coords_in_row_covered_by_sensor: IntervalSet<isize> = sensors
+        .iter()
+        .filter_map(|sensor| sensor.interval_within_x_at_row(row))
+        .collect();
+
+    for sensor in sensors {
+        if sensor.closest_beacon.y == row {
+            coords_in_row_covered_by_sensor.remove(Interval::point(sensor.closest_beacon.x));
+        }
+    }
+
+    coords_in_row_covered_by_sensor.total_length() as usize
+}
+
+/// Rows are searched for the distress beacon in bands of this many, one band per job at a time
+const ROWS_PER_BAND: isize = 10_000;
+
+/// The column of the first position in `rows` that no sensor covers, with its row
+fn find_uncovered(sensors: &[Sensor], rows: Range<isize>, max_coord: isize) -> Option<Coord> {
+    for row in rows {
+        let coords_in_row_covered_by_sensor: IntervalSet<isize> = sensors
+            .iter()
+            .filter_map(|sensor| sensor.interval_within_x_at_row(row))
+            .collect();
+        let possible_locations =
+            coords_in_row_covered_by_sensor.complement(Interval::new(0, max_coord));
+        if let Some(interval) = possible_locations.iter().next() {
+            return Some(Coord::new(interval.min, row));
+        }
+    }
+    None
+}
+
+pub fn part_2(sensors: &[Sensor], max_coord: isize) -> isize {
+    let bands: Vec<Range<isize>> = (0..max_coord)
+        .step_by(ROWS_PER_BAND as usize)
+        .map(|start| start..(start + ROWS_PER_BAND).min(max_coord))
+        .collect();
+    let beacon = Pool::default()
+        .find_map_first(bands, |rows| find_uncovered(sensors, rows, max_coord))
+        .unwrap_or(Coord::new(0, 0));
+    beacon.x * 4_000_000 + beacon.y
+}
+
+pub struct Day15 {
+    /// The row to count covered positions on in part 1
+    pub row: isize,
+    /// The largest coordinate the distress beacon can have in part 2
+    pub bound: isize,
+}
+
+impl Default for Day15 {
+    fn default() -> Day15 {
+        Day15 {
+            row: 2_000_000,
+            bound: 4_000_000,
+        }
+    }
+}
+
+impl Params for Day15 {
+    fn example() -> Day15 {
+        Day15 { row: 10, bound: 20 }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("row", self.row, "row to count covered positions on"),
+            Param::new(
+                "bound",
+                self.bound,
+                "largest coordinate of the distress beacon",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "row" => self.row = parse_value(name, value)?,
+            "bound" => self.bound = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size, self.bound))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input, self.row)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> isize {
+        part_2(input, self.bound)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("inputs/example.txt")
+                .part_1(26)
+                .part_2(56000011),
+            Example::new("inputs/example.txt")
+                .param("row", 11)
+                .part_1(28),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::example_tests!(Day15);
+
+    #[test]
+    fn test_validate() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(
+            validate("Sensor at x=9 y=16: closest beacon is at x=10, y=16"),
+            vec![Diagnostic::new(
+                1,
+                14,
+                "', y='",
+                "Sensor at x=9 y=16: closest beacon is at x=10, y=16"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(4), 50, 1000);
+        assert_eq!(input, generate(&mut Rng::new(4), 50, 1000));
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(input.lines().count(), 50);
+
+        let sensors = parse_input(input.lines().map(String::from));
+        assert!(part_1(&sensors, 500) > 0);
+    }
+}
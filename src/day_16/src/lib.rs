@@ -0,0 +1,677 @@
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::count;
+use shared::error::{ParamError, ParseError};
+use shared::examples::Example;
+use shared::generate::Rng;
+use shared::params::{parse_value, Param, Params};
+use shared::search::bfs_distances;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, numbered_lines, Diagnostic};
+
+lazy_static! {
+    static ref INPUT_RE: Regex = Regex::new(
+        r"^Valve ([^ ]*) has flow rate=([0-9]*); tunnels? leads? to valves? ([A-Z, ]*)$"
+    )
+    .unwrap();
+}
+
+struct Valve {
+    name: String,
+    flow_rate: usize,
+    neighbors: Vec<String>,
+}
+
+pub struct CaveMap {
+    valves_by_name: HashMap<String, Valve>,
+    non_zero_valves: HashSet<String>,
+    distance_map: HashMap<(String, String), usize>,
+}
+
+impl CaveMap {
+    fn all_valves(&self) -> HashSet<String> {
+        self.valves_by_name.keys().cloned().collect()
+    }
+
+    fn flow_rate_for_valve(&self, valve_name: &String) -> usize {
+        self.valves_by_name.get(valve_name).unwrap().flow_rate
+    }
+
+    fn get_neighbors_for_valve(&self, valve_name: &String) -> &Vec<String> {
+        &self.valves_by_name.get(valve_name).unwrap().neighbors
+    }
+
+    fn get_distance(&self, start: &String, end: &String) -> usize {
+        if start == end {
+            return 0;
+        }
+        *self
+            .distance_map
+            .get(&(start.clone(), end.clone()))
+            .unwrap()
+    }
+
+    fn _compute_distance_map(&mut self) {
+        for start in self.all_valves() {
+            let distances = bfs_distances([start.clone()], |valve| {
+                self.get_neighbors_for_valve(valve).iter().cloned()
+            });
+            for (end, distance) in distances {
+                if start != end {
+                    self.distance_map.insert((start.clone(), end), distance);
+                }
+            }
+        }
+    }
+}
+
+/// The destination of a move is only kept to make plans readable when debugging.
+#[allow(dead_code)]
+#[derive(Clone)]
+enum Action {
+    MoveTo(String),
+    Open(String),
+}
+
+#[derive(Clone)]
+struct Plan {
+    actions: Vec<Action>,
+    total_time: usize,
+    current_valve: String,
+    closed_valves: HashSet<String>,
+}
+
+impl Plan {
+    fn next_plans(self, cave_map: &CaveMap) -> Vec<Plan> {
+        let mut next_plans: Vec<Plan> = Vec::new();
+        for closed_valve in self.closed_valves.intersection(&cave_map.non_zero_valves) {
+            let distance = cave_map.get_distance(&self.current_valve, closed_valve);
+            let mut next_plan_actions = self.actions.clone();
+            for _ in 0..distance {
+                next_plan_actions.push(Action::MoveTo(closed_valve.clone()));
+            }
+
+            next_plan_actions.push(Action::Open(closed_valve.clone()));
+            let mut closed_valves = self.closed_valves.clone();
+            closed_valves.remove(closed_valve);
+
+            next_plans.push(Plan {
+                actions: next_plan_actions,
+                total_time: self.total_time,
+                current_valve: closed_valve.clone(),
+                closed_valves,
+            })
+        }
+
+        next_plans
+    }
+
+    fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    fn all_non_zero_valves_open(&self, cave_map: &CaveMap) -> bool {
+        cave_map.non_zero_valves.is_disjoint(&self.closed_valves)
+    }
+
+    fn is_complete(&self, cave_map: &CaveMap) -> bool {
+        self.all_non_zero_valves_open(cave_map) || self.len() >= self.total_time
+    }
+
+    fn final_score(&self, cave_map: &CaveMap) -> usize {
+        let mut total_pressure_released: usize = 0;
+        for (time, action) in self.actions.iter().enumerate() {
+            match action {
+                Action::MoveTo(_) => (),
+                Action::Open(valve_name) => {
+                    let released_pressure =
+                        cave_map.flow_rate_for_valve(valve_name) * (self.total_time - (time + 1));
+                    total_pressure_released += released_pressure;
+                }
+            }
+        }
+        total_pressure_released
+    }
+
+    fn upper_bound_score(&self, cave_map: &CaveMap) -> usize {
+        let final_score_so_far = self.final_score(cave_map);
+        let mut upper_bound_score = final_score_so_far;
+
+        let remaining_time = self.total_time - self.len();
+        for valve in &self.closed_valves {
+            let distance = cave_map.get_distance(&self.current_valve, valve);
+            if distance < remaining_time {
+                upper_bound_score +=
+                    (remaining_time - distance - 1) * cave_map.flow_rate_for_valve(valve);
+            }
+        }
+        upper_bound_score
+    }
+}
+
+#[derive(Clone)]
+struct PlanWithElephant {
+    actions_1: Vec<Action>,
+    actions_2: Vec<Action>,
+    total_time: usize,
+    current_valve_1: String,
+    current_valve_2: String,
+    closed_valves: HashSet<String>,
+}
+
+impl PlanWithElephant {
+    fn shortest_len(&self) -> usize {
+        cmp::min(self.actions_1.len(), self.actions_2.len())
+    }
+
+    fn next_plans(self, cave_map: &CaveMap) -> Vec<PlanWithElephant> {
+        let mut next_plans: Vec<PlanWithElephant> = Vec::new();
+        let closed_valves: HashSet<String> = self
+            .closed_valves
+            .intersection(&cave_map.non_zero_valves)
+            .cloned()
+            .collect();
+        if self.actions_1.len() < self.actions_2.len() {
+            for next_valve in closed_valves {
+                let distance = cave_map.get_distance(&self.current_valve_1, &next_valve);
+                let mut next_plan_actions = self.actions_1.clone();
+                for _ in 0..distance {
+                    next_plan_actions.push(Action::MoveTo(next_valve.clone()));
+                }
+
+                next_plan_actions.push(Action::Open(next_valve.clone()));
+                let mut next_closed_valves = self.closed_valves.clone();
+                next_closed_valves.remove(&next_valve);
+
+                next_plans.push(PlanWithElephant {
+                    actions_1: next_plan_actions,
+                    actions_2: self.actions_2.clone(),
+                    total_time: self.total_time,
+                    current_valve_1: next_valve,
+                    current_valve_2: self.current_valve_2.clone(),
+                    closed_valves: next_closed_valves,
+                })
+            }
+        } else {
+            for next_valve in closed_valves {
+                let distance = cave_map.get_distance(&self.current_valve_2, &next_valve);
+                let mut next_plan_actions = self.actions_2.clone();
+                for _ in 0..distance {
+                    next_plan_actions.push(Action::MoveTo(next_valve.clone()));
+                }
+
+                next_plan_actions.push(Action::Open(next_valve.clone()));
+                let mut next_closed_valves = self.closed_valves.clone();
+                next_closed_valves.remove(&next_valve);
+
+                next_plans.push(PlanWithElephant {
+                    actions_1: self.actions_1.clone(),
+                    actions_2: next_plan_actions,
+                    total_time: self.total_time,
+                    current_valve_1: self.current_valve_1.clone(),
+                    current_valve_2: next_valve,
+                    closed_valves: next_closed_valves,
+                })
+            }
+        }
+
+        next_plans
+    }
+
+    fn all_non_zero_valves_open(&self, cave_map: &CaveMap) -> bool {
+        cave_map.non_zero_valves.is_disjoint(&self.closed_valves)
+    }
+
+    fn is_complete(&self, cave_map: &CaveMap) -> bool {
+        self.all_non_zero_valves_open(cave_map) || self.shortest_len() >= self.total_time
+    }
+
+    fn final_score(&self, cave_map: &CaveMap) -> usize {
+        let mut total_pressure_released: usize = 0;
+        for time in 0..self.total_time {
+            if time < self.actions_1.len() {
+                match &self.actions_1[time] {
+                    Action::MoveTo(_) => (),
+                    Action::Open(valve_name) => {
+                        let released_pressure = cave_map.flow_rate_for_valve(valve_name)
+                            * (self.total_time - (time + 1));
+                        total_pressure_released += released_pressure;
+                    }
+                }
+            }
+            if time < self.actions_2.len() {
+                match &self.actions_2[time] {
+                    Action::MoveTo(_) => (),
+                    Action::Open(valve_name) => {
+                        let released_pressure = cave_map.flow_rate_for_valve(valve_name)
+                            * (self.total_time - (time + 1));
+                        total_pressure_released += released_pressure;
+                    }
+                }
+            }
+        }
+        total_pressure_released
+    }
+
+    fn upper_bound_score(&self, cave_map: &CaveMap) -> usize {
+        let final_score_so_far = self.final_score(cave_map);
+        let mut upper_bound_score = final_score_so_far;
+
+        let remaining_time = self.total_time - self.shortest_len();
+        for valve in &self.closed_valves {
+            let distance_1 = cave_map.get_distance(&self.current_valve_1, valve);
+            let distance_2 = cave_map.get_distance(&self.current_valve_2, valve);
+            let distance = cmp::min(distance_1, distance_2);
+            if distance < remaining_time {
+                upper_bound_score +=
+                    (remaining_time - distance - 1) * cave_map.flow_rate_for_valve(valve);
+            }
+        }
+        upper_bound_score
+    }
+}
+
+fn parse_line(line: String) -> Valve {
+    let captures = INPUT_RE.captures(&line).unwrap();
+    let valve_name: String = captures.get(1).unwrap().as_str().to_string();
+    let flow_rate: usize = captures.get(2).unwrap().as_str().parse::<usize>().unwrap();
+    let destination_valves: Vec<String> = captures
+        .get(3)
+        .unwrap()
+        .as_str()
+        .split(", ")
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>();
+
+    Valve {
+        name: valve_name.clone(),
+        flow_rate,
+        neighbors: destination_valves,
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> CaveMap {
+    let valves_by_name: HashMap<String, Valve> = reader
+        .map(|line| {
+            let valve = parse_line(line);
+            (valve.name.clone(), valve)
+        })
+        .collect();
+    let non_zero_valves: HashSet<String> = valves_by_name
+        .iter()
+        .filter(|(_, valve)| valve.flow_rate > 0)
+        .map(|(valve_name, _)| valve_name.clone())
+        .collect();
+
+    let mut cave_map = CaveMap {
+        valves_by_name,
+        non_zero_valves,
+        distance_map: HashMap::new(),
+    };
+    cave_map._compute_distance_map();
+    cave_map
+}
+
+/// Every tunnel leads to a valve that has a line of its own. Assumes each
+/// line has the right shape.
+fn check_tunnels(input: &str) -> Vec<Diagnostic> {
+    let valves: HashSet<&str> = numbered_lines(input)
+        .filter_map(|(_, line)| line.split(' ').nth(1))
+        .collect();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        // the list follows the last word, "valve" or "valves"
+        let Some(list_start) = line.rfind(" valve").and_then(|word_start| {
+            let word_end = word_start + 1 + line[word_start + 1..].find(' ')?;
+            Some(word_end + 1)
+        }) else {
+            continue;
+        };
+        let mut column = list_start + 1;
+        for tunnel in line[list_start..].split(", ") {
+            if !valves.contains(tunnel) {
+                diagnostics.push(Diagnostic::new(line_number, column, "a known valve", line));
+            }
+            column += tunnel.len() + 2;
+        }
+    }
+    diagnostics
+}
+
+/// One valve per line, with its flow rate and the valves its tunnels lead to
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let is_name_char = |c: char| c.is_ascii_uppercase();
+    let mut diagnostics = check_lines(input, |scanner| {
+        scanner.literal("Valve ")?;
+        scanner.take_while("a valve name", is_name_char)?;
+        scanner.literal(" has flow rate=")?;
+        scanner.unsigned()?;
+        scanner.literal("; tunnel")?;
+        scanner.optional("s");
+        scanner.literal(" lead")?;
+        scanner.optional("s");
+        scanner.literal(" to valve")?;
+        scanner.optional("s");
+        scanner.literal(" ")?;
+        loop {
+            scanner.take_while("a valve name", is_name_char)?;
+            if !scanner.optional(", ") {
+                break;
+            }
+        }
+        scanner.end()
+    });
+    if diagnostics.is_empty() {
+        diagnostics = check_tunnels(input);
+    }
+    diagnostics
+}
+
+/// Valve names are two capital letters, so a cave has at most this many valves
+pub const MAX_VALVES: usize = 26 * 26;
+
+/// The number of valves with a flow rate in the puzzle input. The search in
+/// part 2 grows quickly with it, so generated caves keep to it by default.
+pub const N_FLOWING_VALVES: usize = 15;
+
+/// A connected cave of `n_valves` valves, one of which is `start`. Another
+/// `n_flowing` valves get a flow rate, the rest are stuck at 0 like `start`.
+pub fn generate(rng: &mut Rng, n_valves: usize, n_flowing: usize, start: &str) -> String {
+    let mut names: Vec<String> = ('A'..='Z')
+        .flat_map(|first| ('A'..='Z').map(move |second| format!("{}{}", first, second)))
+        .filter(|name| name != start)
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(n_valves.clamp(2, MAX_VALVES) - 1);
+    names.insert(0, start.to_string());
+    let n_valves = names.len();
+
+    // a random tree keeps every valve reachable, the extra tunnels add loops
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); n_valves];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !neighbors[a].contains(&b) {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+    };
+    for idx in 1..n_valves {
+        connect(idx, rng.index(idx));
+    }
+    for _ in 0..n_valves / 2 {
+        connect(rng.index(n_valves), rng.index(n_valves));
+    }
+
+    let mut flow_rates: Vec<i64> = vec![0; n_valves];
+    let mut flowing: Vec<usize> = (1..n_valves).collect();
+    rng.shuffle(&mut flowing);
+    for idx in flowing.into_iter().take(n_flowing) {
+        flow_rates[idx] = rng.range(1..=25);
+    }
+
+    let mut lines: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            let tunnels: Vec<&str> = neighbors[idx]
+                .iter()
+                .map(|neighbor| names[*neighbor].as_str())
+                .collect();
+            let leads_to = if tunnels.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}\n",
+                name,
+                flow_rates[idx],
+                leads_to,
+                tunnels.join(", ")
+            )
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+pub fn part_1(cave_map: &CaveMap, time_available: usize, starting_valve_name: String) -> usize {
+    let mut best_plan_score: usize = 0;
+    let mut candidate_plans: Vec<Plan> = vec![Plan {
+        actions: vec![],
+        total_time: time_available,
+        current_valve: starting_valve_name,
+        closed_valves: cave_map.all_valves(),
+    }];
+
+    let (mut n_explored, mut n_pruned): (usize, usize) = (0, 0);
+    while let Some(depth_first_candidate) = candidate_plans.pop() {
+        n_explored += 1;
+        let next_plans = depth_first_candidate.next_plans(cave_map);
+        for next_plan in next_plans {
+            if next_plan.is_complete(cave_map) {
+                if next_plan.final_score(cave_map) > best_plan_score {
+                    best_plan_score = next_plan.final_score(cave_map);
+                }
+            } else {
+                let upper_bound_score = next_plan.upper_bound_score(cave_map);
+                if upper_bound_score > best_plan_score {
+                    candidate_plans.push(next_plan);
+                } else {
+                    n_pruned += 1;
+                }
+            }
+        }
+    }
+    count!("plans_explored", n_explored);
+    count!("plans_pruned", n_pruned);
+    best_plan_score
+}
+
+pub fn part_2(cave_map: &CaveMap, time_available: usize, starting_valve_name: String) -> usize {
+    let mut best_plan_score: usize = 0;
+    let mut candidate_plans: Vec<PlanWithElephant> = vec![PlanWithElephant {
+        actions_1: vec![],
+        actions_2: vec![],
+        total_time: time_available,
+        current_valve_1: starting_valve_name.clone(),
+        current_valve_2: starting_valve_name,
+        closed_valves: cave_map.all_valves(),
+    }];
+
+    let (mut n_explored, mut n_pruned): (usize, usize) = (0, 0);
+    while let Some(depth_first_candidate) = candidate_plans.pop() {
+        n_explored += 1;
+        let next_plans = depth_first_candidate.next_plans(cave_map);
+        for next_plan in next_plans {
+            if next_plan.is_complete(cave_map) {
+                if next_plan.final_score(cave_map) > best_plan_score {
+                    best_plan_score = next_plan.final_score(cave_map);
+                }
+            } else {
+                let upper_bound_score = next_plan.upper_bound_score(cave_map);
+                if upper_bound_score > best_plan_score {
+                    candidate_plans.push(next_plan);
+                } else {
+                    n_pruned += 1;
+                }
+            }
+        }
+    }
+    count!("plans_explored", n_explored);
+    count!("plans_pruned", n_pruned);
+    best_plan_score
+}
+
+pub struct Day16 {
+    pub minutes_1: usize,
+    pub minutes_2: usize,
+    pub start: String,
+}
+
+impl Default for Day16 {
+    fn default() -> Day16 {
+        Day16 {
+            minutes_1: 30,
+            minutes_2: 26,
+            start: "AA".to_string(),
+        }
+    }
+}
+
+impl Params for Day16 {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "minutes-1",
+                self.minutes_1,
+                "minutes before the eruption in part 1",
+            ),
+            Param::new(
+                "minutes-2",
+                self.minutes_2,
+                "minutes left after teaching the elephant",
+            ),
+            Param::new("start", &self.start, "valve to start at"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "minutes-1" => self.minutes_1 = parse_value(name, value)?,
+            "minutes-2" => self.minutes_2 = parse_value(name, value)?,
+            "start" => self.start = value.to_string(),
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day16 {
+    type Input = CaveMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        let cave_map = parse_input(input.lines().map(String::from));
+        if !cave_map.valves_by_name.contains_key(&self.start) {
+            let message = format!("no valve {} to start at", self.start);
+            return Err(ParseError::new(message));
+        }
+        Ok(cave_map)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size, N_FLOWING_VALVES, &self.start))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input, self.minutes_1, self.start.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input, self.minutes_2, self.start.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(1651).part_2(1707)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day16);
+
+    #[test]
+    fn test_parse_line() {
+        parse_line("Valve II has flow rate=0; tunnels lead to valves AA, JJ".to_string());
+        parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        parse_input(
+            AocBufReader::from_path("inputs/part_1.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+    }
+
+    #[test]
+    fn test_scoring() {
+        let cave_map = parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+
+        let plan = Plan {
+            actions: vec![
+                Action::MoveTo("DD".to_string()),
+                Action::Open("DD".to_string()),
+                Action::MoveTo("CC".to_string()),
+                Action::MoveTo("BB".to_string()),
+                Action::Open("BB".to_string()),
+                Action::MoveTo("AA".to_string()),
+                Action::MoveTo("II".to_string()),
+                Action::MoveTo("JJ".to_string()),
+                Action::Open("JJ".to_string()),
+                Action::MoveTo("II".to_string()),
+                Action::MoveTo("AA".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("EE".to_string()),
+                Action::MoveTo("FF".to_string()),
+                Action::MoveTo("GG".to_string()),
+                Action::MoveTo("HH".to_string()),
+                Action::Open("HH".to_string()),
+                Action::MoveTo("GG".to_string()),
+                Action::MoveTo("FF".to_string()),
+                Action::MoveTo("EE".to_string()),
+                Action::Open("EE".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("CC".to_string()),
+                Action::Open("CC".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("DD".to_string()),
+                Action::MoveTo("DD".to_string()),
+            ],
+            total_time: 30,
+            current_valve: "AA".to_string(),
+            closed_valves: HashSet::new(),
+        };
+        assert_eq!(plan.final_score(&cave_map), 1651);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(5), 30, 5, "AA");
+        assert_eq!(input, generate(&mut Rng::new(5), 30, 5, "AA"));
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(input.lines().count(), 30);
+        assert_eq!(
+            input
+                .lines()
+                .filter(|line| !line.contains("rate=0;"))
+                .count(),
+            5
+        );
+        assert!(input.contains("Valve AA has flow rate=0;"));
+
+        let cave_map = parse_input(input.lines().map(String::from));
+        assert!(part_1(&cave_map, 30, "AA".to_string()) > 0);
+    }
+}
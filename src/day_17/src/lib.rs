@@ -0,0 +1,621 @@
+use std::cmp;
+use std::collections::HashSet;
+
+use shared::animate::{Animated, Colour, Glyph, Render, Scene};
+use shared::cycle::{self, Cycle};
+use shared::error::{ParamError, ParseError};
+use shared::examples::Example;
+use shared::generate::Rng;
+use shared::geom::{Point2, Vector2};
+use shared::params::{parse_value, Param, Params};
+use shared::sim::{Driver, Simulation};
+use shared::solution::{Extra, Solution};
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+enum JetDirection {
+    Left,
+    Right,
+}
+
+#[derive(Clone)]
+pub struct JetIterator {
+    _string: String,
+    _len: usize,
+    _idx: usize,
+}
+
+impl JetIterator {
+    fn new(string: String) -> JetIterator {
+        let _len = string.len();
+        let _idx = 0;
+        JetIterator {
+            _string: string,
+            _len,
+            _idx,
+        }
+    }
+
+    fn next(&mut self) -> JetDirection {
+        let jet_direction = match self._string.chars().nth(self._idx).unwrap() {
+            '>' => JetDirection::Right,
+            '<' => JetDirection::Left,
+            _ => {
+                panic!("unknown character in jet string");
+            }
+        };
+        self._idx += 1;
+        if self._idx == self._len {
+            self._idx = 0;
+        }
+
+        jet_direction
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum RockShape {
+    Horizontal,
+    Plus,
+    RightAngle,
+    Vertical,
+    Square,
+}
+
+#[derive(Clone)]
+struct RockIterator {
+    _rocks: [RockShape; 5],
+    _len: usize,
+    _idx: usize,
+}
+
+impl RockIterator {
+    fn new() -> RockIterator {
+        let _rocks: [RockShape; 5] = [
+            RockShape::Horizontal,
+            RockShape::Plus,
+            RockShape::RightAngle,
+            RockShape::Vertical,
+            RockShape::Square,
+        ];
+        let _len: usize = 5;
+        let _idx: usize = 0;
+        RockIterator { _rocks, _len, _idx }
+    }
+
+    fn next(&mut self) -> RockShape {
+        let return_value = self._rocks[self._idx];
+        self._idx += 1;
+        if self._idx == self._len {
+            self._idx = 0;
+        }
+        return_value
+    }
+}
+
+type Coord = Point2<isize>;
+
+struct Rock {
+    rock_shape: RockShape,
+    left_most_bottom: Coord,
+}
+
+impl Rock {
+    fn all_coords(&self) -> HashSet<Coord> {
+        match self.rock_shape {
+            RockShape::Horizontal => vec![
+                self.left_most_bottom,
+                self.left_most_bottom + Vector2::new(1, 0),
+                self.left_most_bottom + Vector2::new(2, 0),
+                self.left_most_bottom + Vector2::new(3, 0),
+            ]
+            .into_iter()
+            .collect::<HashSet<Coord>>(),
+            RockShape::Plus => vec![
+                self.left_most_bottom,
+                self.left_most_bottom + Vector2::new(-1, 1),
+                self.left_most_bottom + Vector2::new(0, 1),
+                self.left_most_bottom + Vector2::new(1, 1),
+                self.left_most_bottom + Vector2::new(0, 2),
+            ]
+            .into_iter()
+            .collect::<HashSet<Coord>>(),
+            RockShape::RightAngle => vec![
+                self.left_most_bottom,
+                self.left_most_bottom + Vector2::new(1, 0),
+                self.left_most_bottom + Vector2::new(2, 0),
+                self.left_most_bottom + Vector2::new(2, 1),
+                self.left_most_bottom + Vector2::new(2, 2),
+            ]
+            .into_iter()
+            .collect::<HashSet<Coord>>(),
+            RockShape::Vertical => vec![
+                self.left_most_bottom,
+                self.left_most_bottom + Vector2::new(0, 1),
+                self.left_most_bottom + Vector2::new(0, 2),
+                self.left_most_bottom + Vector2::new(0, 3),
+            ]
+            .into_iter()
+            .collect::<HashSet<Coord>>(),
+            RockShape::Square => vec![
+                self.left_most_bottom,
+                self.left_most_bottom + Vector2::new(1, 0),
+                self.left_most_bottom + Vector2::new(0, 1),
+                self.left_most_bottom + Vector2::new(1, 1),
+            ]
+            .into_iter()
+            .collect::<HashSet<Coord>>(),
+        }
+    }
+
+    fn left_most_coord(&self) -> isize {
+        self.all_coords().iter().map(|coord| coord.x).min().unwrap()
+    }
+
+    fn right_most_coord(&self) -> isize {
+        self.all_coords().iter().map(|coord| coord.x).max().unwrap()
+    }
+
+    fn new_in_chamber(
+        rock_shape: RockShape,
+        left_edge_coord: isize,
+        bottom_edge_coord: isize,
+    ) -> Rock {
+        let left_most_bottom: Coord = match rock_shape {
+            RockShape::Horizontal => Coord::new(left_edge_coord, bottom_edge_coord),
+            RockShape::Plus => Coord::new(left_edge_coord, bottom_edge_coord) + Vector2::new(1, 0),
+            RockShape::RightAngle => Coord::new(left_edge_coord, bottom_edge_coord),
+            RockShape::Vertical => Coord::new(left_edge_coord, bottom_edge_coord),
+            RockShape::Square => Coord::new(left_edge_coord, bottom_edge_coord),
+        };
+        Rock {
+            rock_shape,
+            left_most_bottom,
+        }
+    }
+
+    fn attempt_to_blow(
+        &mut self,
+        chamber_rocks: &HashSet<Coord>,
+        chamber_width: isize,
+        jet_direction: JetDirection,
+    ) {
+        let new_rock_position = match jet_direction {
+            JetDirection::Left => self.left_most_bottom + Vector2::new(-1, 0),
+            JetDirection::Right => self.left_most_bottom + Vector2::new(1, 0),
+        };
+
+        let new_rock = Rock {
+            rock_shape: self.rock_shape,
+            left_most_bottom: new_rock_position,
+        };
+        let rock_hits_other_rocks: bool = !new_rock
+            .all_coords()
+            .intersection(chamber_rocks)
+            .cloned()
+            .collect::<HashSet<Coord>>()
+            .is_empty();
+        let rock_hits_wall: bool =
+            new_rock.left_most_coord() <= 0 || new_rock.right_most_coord() > chamber_width;
+        if !rock_hits_other_rocks && !rock_hits_wall {
+            self.left_most_bottom = new_rock_position;
+        }
+    }
+
+    fn attempt_to_fall(&mut self, chamber_rocks: &HashSet<Coord>) -> bool {
+        let new_rock_position: Coord = self.left_most_bottom + Vector2::new(0, -1);
+        let new_rock = Rock {
+            rock_shape: self.rock_shape,
+            left_most_bottom: new_rock_position,
+        };
+        if new_rock_position.y == 0 {
+            // we hit the bottom
+            return false;
+        }
+        if !(new_rock
+            .all_coords()
+            .intersection(chamber_rocks)
+            .cloned()
+            .collect::<HashSet<Coord>>()
+            .is_empty())
+        {
+            // we run into other rocks
+            return false;
+        }
+
+        self.left_most_bottom = new_rock_position;
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Chamber {
+    rocks: HashSet<Coord>,
+    rock_iterator: RockIterator,
+    jet_iterator: JetIterator,
+    width: isize,
+}
+
+impl Chamber {
+    pub fn new(width: isize, jet_iterator: JetIterator) -> Chamber {
+        let rock_iterator = RockIterator::new();
+        Chamber {
+            rocks: HashSet::new(),
+            rock_iterator,
+            jet_iterator,
+            width,
+        }
+    }
+
+    fn add_rock(&mut self) {
+        let bottom_edge_coord: isize = self.max_y_coord() + 4;
+        let left_edge_coord: isize = 3;
+
+        let rock_shape = self.rock_iterator.next();
+        let mut rock = Rock::new_in_chamber(rock_shape, left_edge_coord, bottom_edge_coord);
+        loop {
+            rock.attempt_to_blow(&self.rocks, self.width, self.jet_iterator.next());
+            let rock_moved = rock.attempt_to_fall(&self.rocks);
+            if !rock_moved {
+                self.rocks.extend(rock.all_coords());
+                break;
+            }
+        }
+        self._prune();
+    }
+
+    fn _prune(&mut self) {
+        let max_y_coord = self.max_y_coord();
+        let pruned_rocks: HashSet<Coord> = self
+            .rocks
+            .iter()
+            .filter(|coord| coord.y >= max_y_coord - 70)
+            .cloned()
+            .collect();
+        self.rocks = pruned_rocks;
+    }
+
+    fn hash(&self) -> String {
+        let max_y_coord = self.max_y_coord();
+        let max_x_coord = self.max_x_coord();
+        let mut rel_coord_str: Vec<String> = vec![];
+        for coord in &self.rocks {
+            rel_coord_str.push(format!(
+                "{},{},",
+                max_y_coord - coord.y,
+                max_x_coord - coord.x
+            ));
+        }
+        rel_coord_str.sort();
+        rel_coord_str.push(format!(
+            "{},{}",
+            self.rock_iterator._idx, self.jet_iterator._idx
+        ));
+        rel_coord_str.into_iter().collect::<String>()
+    }
+
+    fn max_y_coord(&self) -> isize {
+        if self.rocks.is_empty() {
+            0
+        } else {
+            self.rocks.iter().map(|coord| coord.y).max().unwrap()
+        }
+    }
+
+    fn max_x_coord(&self) -> isize {
+        if self.rocks.is_empty() {
+            0
+        } else {
+            self.rocks.iter().map(|coord| coord.x).max().unwrap()
+        }
+    }
+
+    fn tower_height(&self) -> isize {
+        self.max_y_coord()
+    }
+}
+
+/// What `aoc animate` draws in the chamber
+pub enum ChamberCell {
+    Rock,
+    Wall,
+    Floor,
+}
+
+/// Rocks rise with `y`, so rows are drawn at `-y` to keep the tower upright
+impl Render for Chamber {
+    type Cell = ChamberCell;
+
+    fn cells(&self) -> Vec<(Point2<isize>, ChamberCell)> {
+        let top = self.tower_height() + 7;
+        let bottom = cmp::max(self.tower_height() - 70, 0);
+        let mut cells: Vec<(Point2<isize>, ChamberCell)> = self
+            .rocks
+            .iter()
+            .map(|coord| (Point2::new(coord.x, -coord.y), ChamberCell::Rock))
+            .collect();
+        for y in cmp::max(bottom, 1)..=top {
+            cells.push((Point2::new(0, -y), ChamberCell::Wall));
+            cells.push((Point2::new(self.width + 1, -y), ChamberCell::Wall));
+        }
+        // the floor until it is pruned away
+        if bottom == 0 {
+            cells.extend((0..=self.width + 1).map(|x| (Point2::new(x, 0), ChamberCell::Floor)));
+        }
+        cells
+    }
+
+    /// The top of the tower and the space above it where the next rock falls
+    fn focus(&self) -> Option<(Point2<isize>, Point2<isize>)> {
+        let top = self.tower_height();
+        Some((
+            Point2::new(0, -(top + 7)),
+            Point2::new(self.width + 1, -cmp::max(top - 12, 0)),
+        ))
+    }
+}
+
+fn chamber_glyph(cell: &ChamberCell) -> Glyph {
+    match cell {
+        ChamberCell::Rock => Glyph::coloured('#', Colour::Cyan),
+        ChamberCell::Wall => Glyph::coloured('|', Colour::Grey),
+        ChamberCell::Floor => Glyph::coloured('-', Colour::Grey),
+    }
+}
+
+/// Each step drops one rock until it comes to rest
+impl Simulation for Chamber {
+    type Snapshot = Chamber;
+
+    fn step(&mut self) -> bool {
+        self.add_rock();
+        true
+    }
+
+    fn snapshot(&self) -> Chamber {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Chamber) {
+        *self = snapshot.clone();
+    }
+}
+
+pub fn part_1(chamber: Chamber, n_rocks: usize) -> isize {
+    let mut driver = Driver::new(chamber);
+    driver.run(n_rocks);
+    driver.sim().tower_height()
+}
+
+/// Drop rocks until the top of the tower repeats. Once `start` rocks have
+/// been dropped, every further `period` rocks add `delta` to its height.
+pub fn find_cycle(chamber: &mut Chamber) -> Cycle {
+    cycle::find_cycle(
+        chamber,
+        Chamber::hash,
+        |chamber| chamber.tower_height() as i64,
+        usize::MAX,
+    )
+    .unwrap()
+}
+
+fn tower_height(cycle: &Cycle, n_rocks: usize) -> isize {
+    cycle.value_at(n_rocks) as isize
+}
+
+pub fn part_2(mut chamber: Chamber, n_rocks: usize) -> isize {
+    tower_height(&find_cycle(&mut chamber), n_rocks)
+}
+
+pub fn parse_input(mut reader: impl Iterator<Item = String>) -> JetIterator {
+    JetIterator::new(reader.next().unwrap())
+}
+
+/// A single line of `<` and `>` jets
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        scanner.take_while("a jet '<' or '>'", |c| c == '<' || c == '>')?;
+        scanner.end()
+    })
+}
+
+/// A line of `n_jets` random jets
+pub fn generate(rng: &mut Rng, n_jets: usize) -> String {
+    let mut jets: String = (0..n_jets.max(1))
+        .map(|_| if rng.chance(0.5) { '<' } else { '>' })
+        .collect();
+    jets.push('\n');
+    jets
+}
+
+pub struct Day17 {
+    pub width: isize,
+    pub rocks_1: usize,
+    pub rocks_2: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Day17 {
+        Day17 {
+            width: 7,
+            rocks_1: 2_022,
+            rocks_2: 1_000_000_000_000,
+        }
+    }
+}
+
+impl Params for Day17 {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("width", self.width, "width of the chamber"),
+            Param::new("rocks-1", self.rocks_1, "rocks to drop in part 1"),
+            Param::new("rocks-2", self.rocks_2, "rocks to drop in part 2"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "width" => self.width = parse_value(name, value)?,
+            "rocks-1" => self.rocks_1 = parse_value(name, value)?,
+            "rocks-2" => self.rocks_2 = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day17 {
+    type Input = JetIterator;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> isize {
+        part_1(Chamber::new(self.width, input.clone()), self.rocks_1)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> isize {
+        part_2(Chamber::new(self.width, input.clone()), self.rocks_2)
+    }
+
+    fn part_2_with_extras(&self, input: &Self::Input) -> (isize, Vec<Extra>) {
+        let cycle = find_cycle(&mut Chamber::new(self.width, input.clone()));
+        let extras = vec![
+            Extra::new("cycle_start", cycle.start),
+            Extra::new("cycle_length", cycle.period),
+            Extra::new("cycle_height", cycle.delta),
+        ];
+        (tower_height(&cycle, self.rocks_2), extras)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt")
+            .part_1(3068)
+            .part_2(1514285714288u64)]
+    }
+
+    fn animation(&self, input: &Self::Input, _part: usize) -> Option<Box<dyn Scene>> {
+        let chamber = Chamber::new(self.width, input.clone());
+        Some(Box::new(Animated::new(chamber, chamber_glyph)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::animate::{render_frame, Viewport};
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day17);
+
+    #[test]
+    fn test_jet_iterator() {
+        let mut reader = AocBufReader::from_path("inputs/example.txt")
+            .unwrap()
+            .map(Result::unwrap);
+        let mut jet_iterator = JetIterator::new(reader.next().unwrap());
+        for _ in 0..1000 {
+            jet_iterator.next();
+        }
+    }
+
+    #[test]
+    fn test_rock_iterator() {
+        let mut rock_iterator = RockIterator::new();
+        for _ in 0..1000 {
+            rock_iterator.next();
+        }
+    }
+
+    #[test]
+    fn test_chamber() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        let chamber = Chamber::new(7, parse_input(input.lines().map(String::from)));
+        let mut driver = Driver::recording(chamber);
+        driver.run(10);
+        let heights: Vec<isize> = driver
+            .history()
+            .iter()
+            .map(|chamber| chamber.tower_height())
+            .collect();
+        assert_eq!(heights, vec![0, 1, 4, 6, 7, 9, 10, 13, 15, 17, 17]);
+
+        driver.seek(3);
+        assert_eq!(driver.sim().tower_height(), 6);
+        driver.seek(2022);
+        assert_eq!(driver.sim().tower_height(), 3068);
+    }
+
+    #[test]
+    fn test_animation() {
+        let day = Day17::example();
+        let jets = day
+            .parse(&std::fs::read_to_string("inputs/example.txt").unwrap())
+            .unwrap();
+        let mut scene = day.animation(&jets, 1).unwrap();
+        (0..3).for_each(|_| {
+            scene.step();
+        });
+
+        let (min, max) = scene.focus().unwrap();
+        let mut viewport = Viewport::new(14, 9);
+        viewport.follow((min, max));
+        assert_eq!(viewport.origin, Point2::new(0, -13));
+        let frame = render_frame(&scene.glyphs(), &viewport, Glyph::new('.'));
+        let lines: Vec<String> = frame
+            .replace("\x1b[36m", "")
+            .replace("\x1b[90m", "")
+            .replace("\x1b[0m", "")
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines[6..],
+            [
+                "|.......|",
+                "|..#....|",
+                "|..#....|",
+                "|####...|",
+                "|..###..|",
+                "|...#...|",
+                "|..####.|",
+                "---------",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        let jet_iterator = parse_input(input.lines().map(String::from));
+        let mut chamber = Chamber::new(7, jet_iterator);
+        let cycle = find_cycle(&mut chamber);
+        assert_eq!(cycle.period, 35);
+        assert_eq!(cycle.delta, 53);
+        assert_eq!(cycle.value_at(10), 17);
+        assert_eq!(cycle.value_at(2022), 3068);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(6), 100);
+        assert_eq!(input, generate(&mut Rng::new(6), 100));
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(input.trim_end().len(), 100);
+
+        let day = Day17::default();
+        let jets = day.parse(&input).unwrap();
+        assert!(day.part_1(&jets) > 0);
+    }
+}
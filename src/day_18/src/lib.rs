@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::generate::Rng;
+use shared::geom::Point3;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+type Coord = Point3<isize>;
+
+fn neighbors(coord: &Coord) -> HashSet<Coord> {
+    coord.neighbors_6().into_iter().collect()
+}
+
+struct BoundingBox {
+    minimum_corner: Coord,
+    maximum_corner: Coord,
+}
+
+impl BoundingBox {
+    fn contains_coord(&self, coord: &Coord) -> bool {
+        self.minimum_corner.x <= coord.x
+            && coord.x <= self.maximum_corner.x
+            && self.minimum_corner.y <= coord.y
+            && coord.y <= self.maximum_corner.y
+            && self.minimum_corner.z <= coord.z
+            && coord.z <= self.maximum_corner.z
+    }
+}
+
+pub struct RockWithHoles {
+    rock_coords: HashSet<Coord>,
+    bounding_box: BoundingBox,
+}
+
+impl RockWithHoles {
+    fn new(rock_coords: HashSet<Coord>) -> RockWithHoles {
+        let min_x: isize = rock_coords.iter().map(|coord| coord.x).min().unwrap();
+        let max_x: isize = rock_coords.iter().map(|coord| coord.x).max().unwrap();
+        let min_y: isize = rock_coords.iter().map(|coord| coord.y).min().unwrap();
+        let max_y: isize = rock_coords.iter().map(|coord| coord.y).max().unwrap();
+        let min_z: isize = rock_coords.iter().map(|coord| coord.z).min().unwrap();
+        let max_z: isize = rock_coords.iter().map(|coord| coord.z).max().unwrap();
+        let bounding_box = BoundingBox {
+            minimum_corner: Coord::new(min_x, min_y, min_z),
+            maximum_corner: Coord::new(max_x, max_y, max_z),
+        };
+
+        RockWithHoles {
+            rock_coords,
+            bounding_box,
+        }
+    }
+
+    fn all_boundary_neighbors(&self) -> HashSet<Coord> {
+        let mut all_boundary_neighbors: HashSet<Coord> = HashSet::new();
+        for coord in &self.rock_coords {
+            all_boundary_neighbors.extend(
+                neighbors(coord)
+                    .difference(&self.rock_coords)
+                    .cloned()
+                    .collect::<HashSet<Coord>>(),
+            );
+        }
+        all_boundary_neighbors
+    }
+
+    fn total_surface_area(&self) -> usize {
+        self.rock_coords
+            .iter()
+            .map(|coord| {
+                neighbors(coord)
+                    .difference(&self.rock_coords)
+                    .cloned()
+                    .collect::<HashSet<Coord>>()
+                    .len()
+            })
+            .sum()
+    }
+
+    fn bubble_surface_area(&self) -> usize {
+        let mut unclassified_neighbors = self.all_boundary_neighbors();
+        let mut bubble_coords: HashSet<Coord> = HashSet::new();
+
+        // iterate through unclassified neighbors and classify as external or internal
+        while !unclassified_neighbors.is_empty() {
+            let unclassified_coord = unclassified_neighbors.iter().next().unwrap();
+            let (is_bubble, coords) = self.classify_coord(*unclassified_coord);
+
+            unclassified_neighbors = unclassified_neighbors
+                .difference(&coords)
+                .cloned()
+                .collect::<HashSet<Coord>>();
+            if is_bubble {
+                bubble_coords.extend(coords);
+            }
+        }
+
+        bubble_coords
+            .iter()
+            .map(|coord| {
+                neighbors(coord)
+                    .difference(&bubble_coords)
+                    .cloned()
+                    .collect::<HashSet<Coord>>()
+                    .len()
+            })
+            .sum()
+    }
+
+    /// Returns a boolean indicating whether this coord belongs to a bubble (true)
+    /// and a set of coordinates that were explored while classifying this coordinate.
+    /// All returned coordinates are `connected` to coord.
+    fn classify_coord(&self, coord: Coord) -> (bool, HashSet<Coord>) {
+        let mut explored_coords: HashSet<Coord> = vec![].into_iter().collect();
+        let mut unexplored_coords: HashSet<Coord> = vec![coord].into_iter().collect();
+        loop {
+            if unexplored_coords.is_empty() {
+                return (true, explored_coords);
+            }
+
+            let coord_to_explore = *unexplored_coords.iter().next().unwrap();
+            unexplored_coords.remove(&coord_to_explore);
+            explored_coords.insert(coord_to_explore);
+            if !self.bounding_box.contains_coord(&coord_to_explore) {
+                // this exterior region breaks out of the bounds of the rock.
+                // we are not in a bubble!
+                break;
+            }
+
+            let new_neighbors_to_explore = neighbors(&coord_to_explore)
+                .difference(&self.rock_coords)
+                .cloned()
+                .collect::<HashSet<Coord>>()
+                .difference(&explored_coords)
+                .cloned()
+                .collect::<HashSet<Coord>>();
+            unexplored_coords.extend(new_neighbors_to_explore);
+        }
+
+        (false, explored_coords)
+    }
+}
+
+fn parse_coords(reader: impl Iterator<Item = String>) -> HashSet<Coord> {
+    reader
+        .map(|line| {
+            let mut split = line.split(",");
+            Coord::new(
+                split.next().unwrap().parse::<isize>().unwrap(),
+                split.next().unwrap().parse::<isize>().unwrap(),
+                split.next().unwrap().parse::<isize>().unwrap(),
+            )
+        })
+        .collect()
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> RockWithHoles {
+    RockWithHoles::new(parse_coords(reader))
+}
+
+/// `x,y,z` cubes
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        scanner.unsigned()?;
+        scanner.literal(",")?;
+        scanner.unsigned()?;
+        scanner.literal(",")?;
+        scanner.unsigned()?;
+        scanner.end()
+    })
+}
+
+/// A lumpy droplet of `n_cubes` cubes, grown one cube at a time next to a
+/// random cube already in it
+pub fn generate(rng: &mut Rng, n_cubes: usize) -> String {
+    // leave room to grow in every direction, since coordinates are unsigned
+    let center = 2 * (n_cubes as f64).cbrt() as isize + 1;
+    let mut cubes: Vec<Coord> = vec![Coord::new(center, center, center)];
+    let mut in_droplet: HashSet<Coord> = cubes.iter().cloned().collect();
+    while cubes.len() < n_cubes {
+        let cube = cubes[rng.index(cubes.len())];
+        let next = cube.neighbors_6()[rng.index(6)];
+        if next.x >= 0 && next.y >= 0 && next.z >= 0 && in_droplet.insert(next) {
+            cubes.push(next);
+        }
+    }
+
+    rng.shuffle(&mut cubes);
+    cubes
+        .iter()
+        .map(|cube| format!("{},{},{}\n", cube.x, cube.y, cube.z))
+        .collect()
+}
+
+pub fn part_1(rock_with_holes: &RockWithHoles) -> usize {
+    rock_with_holes.total_surface_area()
+}
+
+pub fn part_2(rock_with_holes: &RockWithHoles) -> usize {
+    rock_with_holes.total_surface_area() - rock_with_holes.bubble_surface_area()
+}
+
+#[derive(Default)]
+pub struct Day18;
+
+impl Params for Day18 {}
+
+impl Solution for Day18 {
+    type Input = RockWithHoles;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(64).part_2(58)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day18);
+
+    #[test]
+    fn test_parse_input() {
+        let reader = AocBufReader::from_path("inputs/example.txt")
+            .unwrap()
+            .map(Result::unwrap);
+        let coords = parse_coords(reader);
+        assert_eq!(coords.len(), 13);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(7), 500);
+        assert_eq!(input, generate(&mut Rng::new(7), 500));
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(parse_coords(input.lines().map(String::from)).len(), 500);
+
+        let rock_with_holes = parse_input(input.lines().map(String::from));
+        assert!(part_2(&rock_with_holes) <= part_1(&rock_with_holes));
+    }
+}
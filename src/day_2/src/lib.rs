@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use shared::error::ParseError;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+lazy_static! {
+    static ref PART_1_MAPPING: HashMap<(char, char), usize> = {
+        let mut m: HashMap<(char, char), usize> = HashMap::new();
+        m.insert(('A', 'X'), 1 + 3);
+        m.insert(('A', 'Y'), 2 + 6);
+        m.insert(('A', 'Z'), 3);
+        m.insert(('B', 'X'), 1);
+        m.insert(('B', 'Y'), 2 + 3);
+        m.insert(('B', 'Z'), 3 + 6);
+        m.insert(('C', 'X'), 1 + 6);
+        m.insert(('C', 'Y'), 2);
+        m.insert(('C', 'Z'), 3 + 3);
+        m
+    };
+    static ref PART_2_MAPPING: HashMap<(char, char), usize> = {
+        let mut m: HashMap<(char, char), usize> = HashMap::new();
+        m.insert(('A', 'X'), 3);
+        m.insert(('A', 'Y'), 1 + 3);
+        m.insert(('A', 'Z'), 2 + 6);
+        m.insert(('B', 'X'), 1);
+        m.insert(('B', 'Y'), 2 + 3);
+        m.insert(('B', 'Z'), 3 + 6);
+        m.insert(('C', 'X'), 2);
+        m.insert(('C', 'Y'), 3 + 3);
+        m.insert(('C', 'Z'), 1 + 6);
+        m
+    };
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<(char, char)> {
+    let mut input: Vec<(char, char)> = Vec::new();
+    for line in reader {
+        let mut chars = line.split(' ');
+        let char_1: char = chars.next().unwrap().chars().next().unwrap();
+        let char_2: char = chars.next().unwrap().chars().next().unwrap();
+        input.push((char_1, char_2));
+    }
+    input
+}
+
+/// `A X` lines, the opponent's shape and the second column
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        scanner.one_of("ABC")?;
+        scanner.literal(" ")?;
+        scanner.one_of("XYZ")?;
+        scanner.end()
+    })
+}
+
+pub fn part_1(tuples: &[(char, char)]) -> usize {
+    tuples
+        .iter()
+        .map(|tuple| *PART_1_MAPPING.get(tuple).unwrap())
+        .sum()
+}
+
+pub fn part_2(tuples: &[(char, char)]) -> usize {
+    tuples
+        .iter()
+        .map(|tuple| *PART_2_MAPPING.get(tuple).unwrap())
+        .sum()
+}
+
+#[derive(Default)]
+pub struct Day2;
+
+impl Params for Day2 {}
+
+impl Solution for Day2 {
+    type Input = Vec<(char, char)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+}
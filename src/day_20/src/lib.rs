@@ -0,0 +1,323 @@
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::generate::Rng;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, numbered_lines, Diagnostic};
+
+#[derive(Clone)]
+struct Node {
+    value: isize,
+    id: usize,
+    previous: usize,
+    next: usize,
+}
+
+#[derive(Clone)]
+struct LinkedRing {
+    elements: Vec<Node>,
+}
+
+impl LinkedRing {
+    fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    fn get(&self, id: usize) -> &Node {
+        self.elements.get(id).unwrap()
+    }
+
+    fn _push(&mut self, id: usize) {
+        let mut subject = self.get(id).clone();
+        let mut next = self.get(subject.next).clone();
+        let mut previous = self.get(subject.previous).clone();
+        let mut next_next = self.get(next.next).clone();
+
+        let next_next_id = next_next.id;
+        let subject_previous = previous.id;
+
+        subject.previous = next.id;
+        subject.next = next_next_id;
+
+        next.previous = subject_previous;
+        next.next = subject.id;
+
+        previous.next = next.id;
+        next_next.previous = subject.id;
+
+        self.elements[subject.id] = subject.clone();
+        self.elements[next.id] = next.clone();
+        self.elements[previous.id] = previous.clone();
+        self.elements[next_next.id] = next_next.clone();
+    }
+
+    fn push_n(&mut self, id: usize, n: usize) {
+        for _ in 0..n {
+            self._push(id);
+        }
+    }
+
+    fn _pull(&mut self, id: usize) {
+        let mut subject = self.get(id).clone();
+        let mut previous = self.get(subject.previous).clone();
+        let mut next = self.get(subject.next).clone();
+        let mut previous_previous = self.get(previous.previous).clone();
+
+        let previous_previous_id = previous.previous;
+        let subject_next = subject.next;
+
+        subject.previous = previous_previous_id;
+        subject.next = previous.id;
+
+        previous.previous = subject.id;
+        previous.next = subject_next;
+
+        next.previous = previous.id;
+        previous_previous.next = subject.id;
+
+        self.elements[subject.id] = subject.clone();
+        self.elements[previous.id] = previous.clone();
+        self.elements[next.id] = next.clone();
+        self.elements[previous_previous.id] = previous_previous.clone();
+    }
+
+    fn pull_n(&mut self, id: usize, n: usize) {
+        for _ in 0..n {
+            self._pull(id);
+        }
+    }
+
+    fn get_nth_value_after_zero(&self, n: usize) -> isize {
+        let zero_elements = self
+            .elements
+            .iter()
+            .filter(|e| e.value == 0)
+            .collect::<Vec<&Node>>();
+        assert_eq!(zero_elements.len(), 1);
+
+        let mut cursor = zero_elements[0].id;
+        for _ in 0..n {
+            cursor = self.get_next_element_id(cursor);
+        }
+        self.elements.get(cursor).unwrap().value
+    }
+
+    fn get_next_element_id(&self, id: usize) -> usize {
+        self.get(id).next
+    }
+}
+
+impl LinkedRing {
+    fn new(values: &[isize], decryption_key: isize) -> LinkedRing {
+        let n_values = values.len();
+        let mut elements: Vec<Node> = vec![];
+        for (idx, val) in values.iter().enumerate() {
+            let previous = if idx == 0 { n_values - 1 } else { idx - 1 };
+            let next = if idx == n_values - 1 { 0 } else { idx + 1 };
+
+            elements.push(Node {
+                value: val * decryption_key,
+                id: idx,
+                previous,
+                next,
+            });
+        }
+        LinkedRing { elements }
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<isize> {
+    reader
+        .into_iter()
+        .map(|line| line.parse::<isize>().unwrap())
+        .collect()
+}
+
+/// The file has at least two numbers to mix, and the grove coordinates are
+/// counted from its only 0
+fn check_zero(input: &str) -> Vec<Diagnostic> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let zeros: Vec<&(usize, &str)> = lines
+        .iter()
+        .filter(|(_, line)| line.parse::<isize>() == Ok(0))
+        .collect();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    if let [(line_no, line)] = lines[..] {
+        diagnostics.push(Diagnostic::new(
+            line_no,
+            line.len() + 1,
+            "a second number",
+            line,
+        ));
+    }
+    match zeros[..] {
+        [] => {
+            let (line_no, line) = lines.last().copied().unwrap_or((1, ""));
+            diagnostics.push(Diagnostic::new(line_no, 1, "a 0 somewhere", line));
+        }
+        [_] => (),
+        [_, (line_no, line), ..] => {
+            diagnostics.push(Diagnostic::new(
+                *line_no,
+                1,
+                "a number other than 0, which is taken",
+                *line,
+            ));
+        }
+    }
+    diagnostics
+}
+
+/// One integer per line, with a single 0
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_lines(input, |scanner| {
+        scanner.integer()?;
+        scanner.end()
+    });
+    if diagnostics.is_empty() {
+        diagnostics.extend(check_zero(input));
+    }
+    diagnostics
+}
+
+/// `n_numbers` numbers in the range of the puzzle input, exactly one of which is 0
+pub fn generate(rng: &mut Rng, n_numbers: usize) -> String {
+    let n_numbers = n_numbers.max(2);
+    let zero_idx = rng.index(n_numbers);
+    (0..n_numbers)
+        .map(|idx| {
+            let number = match rng.range(-9_999..=9_998) {
+                _ if idx == zero_idx => 0,
+                number if number >= 0 => number + 1,
+                number => number,
+            };
+            format!("{}\n", number)
+        })
+        .collect()
+}
+
+pub fn part_1(values: &[isize]) -> isize {
+    let mut linear_ring = LinkedRing::new(values, 1);
+    for id in 0..linear_ring.len() {
+        let value = linear_ring.elements.get(id).unwrap().value;
+        let n_moves = value.unsigned_abs();
+        if value < 0 {
+            linear_ring.pull_n(id, n_moves);
+        } else if value > 0 {
+            linear_ring.push_n(id, n_moves);
+        }
+    }
+
+    linear_ring.get_nth_value_after_zero(1_000)
+        + linear_ring.get_nth_value_after_zero(2_000)
+        + linear_ring.get_nth_value_after_zero(3_000)
+}
+
+pub fn part_2(values: &[isize]) -> isize {
+    let mut linear_ring = LinkedRing::new(values, 811589153);
+    for _ in 0..10 {
+        for id in 0..linear_ring.len() {
+            let value = linear_ring.elements.get(id).unwrap().value;
+            let n_moves = value % ((linear_ring.len() as isize) - 1);
+            let n_moves = n_moves.unsigned_abs();
+            if value < 0 {
+                linear_ring.pull_n(id, n_moves);
+            } else if value > 0 {
+                linear_ring.push_n(id, n_moves);
+            }
+        }
+    }
+
+    linear_ring.get_nth_value_after_zero(1_000)
+        + linear_ring.get_nth_value_after_zero(2_000)
+        + linear_ring.get_nth_value_after_zero(3_000)
+}
+
+#[derive(Default)]
+pub struct Day20;
+
+impl Params for Day20 {}
+
+impl Solution for Day20 {
+    type Input = Vec<isize>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> isize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> isize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt")
+            .part_1(3)
+            .part_2(1623178306)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day20);
+
+    const EXAMPLE: &str = "1\n2\n-3\n3\n-2\n0\n4\n";
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(EXAMPLE), vec![]);
+        assert_eq!(validate("1\n2\n")[0].expected, "a 0 somewhere");
+        assert_eq!(validate("0\n2\n0\n")[0].line, 3);
+        assert_eq!(validate("0\n")[0].expected, "a second number");
+        assert!(Day20.parse("1\n2\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_input_pt_1() {
+        let values = parse_input(AocBufReader::from_str(EXAMPLE).map(Result::unwrap));
+        let mut ring = LinkedRing::new(&values, 1);
+        ring.push_n(0, 1);
+
+        let mut cursor = 0;
+        for _ in 0..ring.len() + 1 {
+            println!("{}", ring.get(cursor).value);
+            cursor = ring.get_next_element_id(cursor);
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let values = parse_input(AocBufReader::from_str(EXAMPLE).map(Result::unwrap));
+        assert_eq!(part_1(&values), 3);
+        assert_eq!(part_2(&values), 1623178306);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(8), 100);
+        assert_eq!(input, generate(&mut Rng::new(8), 100));
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(input.lines().count(), 100);
+        assert_eq!(input.lines().filter(|line| *line == "0").count(), 1);
+
+        let values = parse_input(input.lines().map(String::from));
+        part_1(&values);
+    }
+}
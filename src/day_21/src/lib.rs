@@ -0,0 +1,279 @@
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, numbered_lines, Diagnostic};
+
+lazy_static! {
+    static ref VALUE_REGEX: Regex = Regex::new(r"^([^:]*): ([0-9]*)$").unwrap();
+    static ref OPERATION_REGEX: Regex =
+        Regex::new(r"^([^:]*): ([^ ]*) ([\+\-\*/]) ([^ ]*)$").unwrap();
+}
+
+enum OperationType {
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+}
+
+struct Operation {
+    operation_type: OperationType,
+    argument_1: String,
+    argument_2: String,
+}
+
+enum MonkeyJob {
+    DoOperation(Operation),
+    Value(isize),
+}
+
+pub struct MonkeyJobs {
+    jobs: HashMap<String, MonkeyJob>,
+}
+
+impl MonkeyJobs {
+    fn new(jobs: HashMap<String, MonkeyJob>) -> MonkeyJobs {
+        MonkeyJobs { jobs }
+    }
+
+    fn get_value(&self, monkey_id: String) -> isize {
+        let monkey_job = self.jobs.get(&monkey_id).unwrap();
+        match monkey_job {
+            MonkeyJob::DoOperation(operation) => {
+                let argument_1 = self.get_value(operation.argument_1.clone());
+                let argument_2 = self.get_value(operation.argument_2.clone());
+                match operation.operation_type {
+                    OperationType::Addition => argument_1 + argument_2,
+                    OperationType::Subtraction => argument_1 - argument_2,
+                    OperationType::Multiplication => argument_1 * argument_2,
+                    OperationType::Division => argument_1 / argument_2,
+                }
+            }
+            MonkeyJob::Value(return_value) => *return_value,
+        }
+    }
+
+    fn test_humn(&self, monkey_id: String, test_value: isize) -> isize {
+        if monkey_id == "humn" {
+            return test_value;
+        }
+
+        let monkey_job = self.jobs.get(&monkey_id).unwrap();
+        match monkey_job {
+            MonkeyJob::DoOperation(operation) => {
+                let argument_1 = self.test_humn(operation.argument_1.clone(), test_value);
+                let argument_2 = self.test_humn(operation.argument_2.clone(), test_value);
+                match operation.operation_type {
+                    OperationType::Addition => argument_1 + argument_2,
+                    OperationType::Subtraction => argument_1 - argument_2,
+                    OperationType::Multiplication => argument_1 * argument_2,
+                    OperationType::Division => argument_1 / argument_2,
+                }
+            }
+            MonkeyJob::Value(return_value) => *return_value,
+        }
+    }
+}
+
+pub fn part_1(monkey_jobs: &MonkeyJobs) -> isize {
+    monkey_jobs.get_value("root".to_string())
+}
+
+pub fn part_2(monkey_jobs: &MonkeyJobs) -> isize {
+    let comparison_argument_1: String;
+    let comparison_argument_2: String;
+    match monkey_jobs.jobs.get("root").unwrap() {
+        MonkeyJob::DoOperation(operation) => {
+            comparison_argument_1 = operation.argument_1.clone();
+            comparison_argument_2 = operation.argument_2.clone();
+        }
+        MonkeyJob::Value(_) => {
+            panic!("expected root to be a binary operation");
+        }
+    }
+
+    let mut humn: isize = match monkey_jobs.jobs.get("humn").unwrap() {
+        MonkeyJob::DoOperation(_) => {
+            panic!("expected humn to be a value")
+        }
+        MonkeyJob::Value(value) => *value,
+    };
+
+    let mut current_abs_error: usize = 0;
+    let mut jitter: isize = 10_000_000_000;
+    loop {
+        let abs_error = (monkey_jobs.test_humn(comparison_argument_1.clone(), humn)
+            - monkey_jobs.test_humn(comparison_argument_2.clone(), humn))
+        .unsigned_abs();
+
+        if abs_error == 0 {
+            break; // we found it!
+        }
+
+        if abs_error > current_abs_error {
+            // we've gone too far! come back in in smaller steps
+            jitter *= -1;
+            jitter /= 10;
+        }
+
+        current_abs_error = abs_error;
+        humn += jitter;
+    }
+
+    humn
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> MonkeyJobs {
+    let mut jobs: HashMap<String, MonkeyJob> = HashMap::new();
+    for line in reader {
+        if let Some(captures) = VALUE_REGEX.captures(&line) {
+            let monkey_id = captures.get(1).unwrap().as_str().to_string();
+            let value = captures.get(2).unwrap().as_str().parse::<isize>().unwrap();
+            jobs.insert(monkey_id, MonkeyJob::Value(value));
+        } else {
+            let captures = OPERATION_REGEX.captures(&line).unwrap();
+            let monkey_id = captures.get(1).unwrap().as_str().to_string();
+            let argument_1 = captures.get(2).unwrap().as_str().to_string();
+            let argument_2 = captures.get(4).unwrap().as_str().to_string();
+
+            let operation: Operation = match captures.get(3).unwrap().as_str() {
+                "+" => Operation {
+                    operation_type: OperationType::Addition,
+                    argument_1,
+                    argument_2,
+                },
+                "-" => Operation {
+                    operation_type: OperationType::Subtraction,
+                    argument_1,
+                    argument_2,
+                },
+                "*" => Operation {
+                    operation_type: OperationType::Multiplication,
+                    argument_1,
+                    argument_2,
+                },
+                "/" => Operation {
+                    operation_type: OperationType::Division,
+                    argument_1,
+                    argument_2,
+                },
+                _ => panic!("Unknown operation type"),
+            };
+            jobs.insert(monkey_id, MonkeyJob::DoOperation(operation));
+        }
+    }
+
+    MonkeyJobs::new(jobs)
+}
+
+/// Every monkey waits for monkeys that have a line of their own, and there
+/// are a `root` and a `humn`. Assumes each line has the right shape.
+fn check_monkeys(input: &str) -> Vec<Diagnostic> {
+    let monkeys: HashSet<&str> = numbered_lines(input)
+        .filter_map(|(_, line)| line.split_once(':'))
+        .map(|(name, _)| name)
+        .collect();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        let words: Vec<&str> = line.split(' ').collect();
+        if let [_, argument_1, _, argument_2] = words[..] {
+            let column_2 = line.len() - argument_2.len() + 1;
+            for (argument, column) in [(argument_1, words[0].len() + 2), (argument_2, column_2)] {
+                if !monkeys.contains(argument) {
+                    diagnostics.push(Diagnostic::new(line_number, column, "a known monkey", line));
+                }
+            }
+        }
+    }
+    let n_lines = input.lines().count();
+    for name in ["root", "humn"] {
+        if !monkeys.contains(name) {
+            let expected = format!("a line for monkey {}", name);
+            diagnostics.push(Diagnostic::new(n_lines + 1, 1, expected, ""));
+        }
+    }
+    diagnostics
+}
+
+/// `name: N` or `name: a + b` jobs, one per monkey
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let is_name_char = |c: char| c.is_ascii_lowercase();
+    let mut diagnostics = check_lines(input, |scanner| {
+        scanner.take_while("a monkey name", is_name_char)?;
+        scanner.literal(": ")?;
+        if scanner.peek().is_some_and(|c| c.is_ascii_digit()) {
+            scanner.unsigned()?;
+        } else {
+            scanner.take_while("a number or a monkey name", is_name_char)?;
+            scanner.literal(" ")?;
+            scanner.one_of("+-*/")?;
+            scanner.literal(" ")?;
+            scanner.take_while("a monkey name", is_name_char)?;
+        }
+        scanner.end()
+    });
+    if diagnostics.is_empty() {
+        diagnostics = check_monkeys(input);
+    }
+    diagnostics
+}
+
+#[derive(Default)]
+pub struct Day21;
+
+impl Params for Day21 {}
+
+impl Solution for Day21 {
+    type Input = MonkeyJobs;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> isize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> isize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(152).part_2(301)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day21);
+
+    #[test]
+    fn test_parse_input() {
+        parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        parse_input(
+            AocBufReader::from_path("inputs/part_1.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+    }
+}
@@ -0,0 +1,492 @@
+use std::collections::HashSet;
+
+use shared::debug;
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::geom::{Direction, Point2};
+use shared::grid::SparseGrid;
+use shared::input::LineGroups;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_not_empty, ensure_valid, paragraphs, Diagnostic, Mismatch, Scanner};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+/// One step from `position`, with rows growing downwards
+fn step(position: Point2<isize>, direction: Direction) -> Point2<isize> {
+    let vector = direction.to_vector::<isize>();
+    Point2::new(position.x + vector.x, position.y - vector.y)
+}
+
+/// The number a direction scores in the password
+fn facing(direction: Direction) -> isize {
+    match direction {
+        Direction::Up => 3,
+        Direction::Left => 2,
+        Direction::Down => 1,
+        Direction::Right => 0,
+    }
+}
+
+/// The tiles on the board, with rows and columns numbered from 1
+pub struct Board {
+    tiles: SparseGrid<Tile>,
+}
+
+impl Board {
+    fn wrap(&self, position: Point2<isize>, direction: Direction) -> Point2<isize> {
+        let row = position.y;
+        let col = position.x;
+        let in_col = || {
+            self.tiles
+                .points()
+                .filter(move |point| point.x == col)
+                .map(|point| point.y)
+        };
+        let in_row = || {
+            self.tiles
+                .points()
+                .filter(move |point| point.y == row)
+                .map(|point| point.x)
+        };
+        match direction {
+            // wrap to the bottom (largest number)
+            Direction::Up => Point2::new(col, in_col().max().unwrap()),
+            // wrap to the top (smallest number)
+            Direction::Down => Point2::new(col, in_col().min().unwrap()),
+            // wrap to the right (largest number)
+            Direction::Left => Point2::new(in_row().max().unwrap(), row),
+            // wrap to the left (smallest number)
+            Direction::Right => Point2::new(in_row().min().unwrap(), row),
+        }
+    }
+
+    fn wrap_cube(&self, position: Point2<isize>) -> (Point2<isize>, Direction) {
+        if position.y == 1 && position.x >= 51 && position.x <= 100 {
+            (Point2::new(1, position.x + 100), Direction::Right)
+        } else if position.y == 1 && position.x >= 101 && position.x <= 150 {
+            (Point2::new(position.x - 100, 200), Direction::Up)
+        } else if position.y >= 1 && position.y <= 50 && position.x == 150 {
+            (Point2::new(100, 151 - position.y), Direction::Left)
+        } else if position.y == 50 && position.x >= 101 && position.x <= 150 {
+            (Point2::new(100, position.x - 50), Direction::Left)
+        } else if position.y >= 51 && position.y <= 100 && position.x == 100 {
+            (Point2::new(position.y + 50, 50), Direction::Up)
+        } else if position.y >= 101 && position.y <= 150 && position.x == 100 {
+            (Point2::new(150, 151 - position.y), Direction::Left)
+        } else if position.y == 150 && position.x >= 51 && position.x <= 100 {
+            (Point2::new(50, position.x + 100), Direction::Left)
+        } else if position.y >= 151 && position.y <= 200 && position.x == 50 {
+            (Point2::new(position.y - 100, 150), Direction::Up)
+        } else if position.y == 200 && position.x >= 1 && position.x <= 50 {
+            (Point2::new(position.x + 100, 1), Direction::Down)
+        } else if position.y >= 151 && position.y <= 200 && position.x == 1 {
+            (Point2::new(position.y - 100, 1), Direction::Down)
+        } else if position.y >= 101 && position.y <= 150 && position.x == 1 {
+            (Point2::new(51, 151 - position.y), Direction::Right)
+        } else if position.y == 101 && position.x >= 1 && position.x <= 50 {
+            (Point2::new(51, position.x + 50), Direction::Right)
+        } else if position.y >= 51 && position.y <= 100 && position.x == 51 {
+            (Point2::new(position.y - 50, 101), Direction::Down)
+        } else if position.y >= 1 && position.y <= 50 && position.x == 51 {
+            (Point2::new(1, 151 - position.y), Direction::Right)
+        } else {
+            panic!(
+                "We've entered wrap cube, but we have an unhandled mapping: ({}, {})",
+                position.y, position.x
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum RightLeft {
+    Right,
+    Left,
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Rotate(RightLeft),
+    Move(usize),
+}
+
+struct Mover {
+    position: Point2<isize>,
+    direction: Direction,
+}
+
+impl Mover {
+    fn new(board: &Board) -> Mover {
+        let top_row = 1;
+        let left_most_column = board
+            .tiles
+            .iter()
+            .filter(|(point, tile)| point.y == top_row && **tile == Tile::Open)
+            .map(|(point, _)| point.x)
+            .min()
+            .unwrap();
+        Mover {
+            position: Point2::new(left_most_column, top_row),
+            direction: Direction::Right,
+        }
+    }
+
+    fn _rotate(&mut self, right_left: RightLeft) {
+        match right_left {
+            RightLeft::Right => self.direction = self.direction.turn_right(),
+            RightLeft::Left => self.direction = self.direction.turn_left(),
+        }
+    }
+
+    fn _move_part_1(&mut self, n_moves: usize, board: &Board) {
+        for _ in 0..n_moves {
+            let mut next = step(self.position, self.direction);
+
+            if !board.tiles.contains(&next) {
+                // this space isn't on the map!
+                next = board.wrap(self.position, self.direction);
+            }
+
+            if board.tiles.get(&next) == Some(&Tile::Wall) {
+                // we ran into a wall!
+                break;
+            }
+
+            self.position = next;
+        }
+    }
+
+    fn _move_part_2(&mut self, n_moves: usize, board: &Board) {
+        for _ in 0..n_moves {
+            let mut next_position = step(self.position, self.direction);
+            let mut next_direction = self.direction;
+
+            if !board.tiles.contains(&next_position) {
+                // this space isn't on the map!
+                (next_position, next_direction) = board.wrap_cube(self.position);
+            }
+
+            if board.tiles.get(&next_position) == Some(&Tile::Wall) {
+                // we ran into a wall!
+                break;
+            }
+
+            self.position = next_position;
+            self.direction = next_direction;
+        }
+    }
+
+    fn execute_instruction_part_1(&mut self, board: &Board, instruction: Instruction) {
+        match instruction {
+            Instruction::Rotate(right_left) => self._rotate(right_left),
+            Instruction::Move(n_moves) => self._move_part_1(n_moves, board),
+        }
+    }
+
+    fn execute_instruction_part_2(&mut self, board: &Board, instruction: Instruction) {
+        match instruction {
+            Instruction::Rotate(right_left) => self._rotate(right_left),
+            Instruction::Move(n_moves) => self._move_part_2(n_moves, board),
+        }
+    }
+}
+
+fn parse_instructions(line: &str) -> Vec<Instruction> {
+    let mut instructions: Vec<Instruction> = Vec::new();
+
+    let chars = line.chars();
+    let mut number_string: String = String::new();
+    for c in chars {
+        if c.is_ascii_digit() {
+            number_string.push(c);
+        } else {
+            if !number_string.is_empty() {
+                instructions.push(Instruction::Move(number_string.parse::<usize>().unwrap()));
+                number_string = String::new();
+            }
+            match c {
+                'R' => {
+                    instructions.push(Instruction::Rotate(RightLeft::Right));
+                }
+                'L' => {
+                    instructions.push(Instruction::Rotate(RightLeft::Left));
+                }
+                _ => {
+                    panic!("unknown instruction character");
+                }
+            }
+        }
+    }
+    if !number_string.is_empty() {
+        instructions.push(Instruction::Move(number_string.parse::<usize>().unwrap()));
+    }
+
+    instructions
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> (Board, Vec<Instruction>) {
+    let (board_lines, instruction_lines) = reader.split_at_blank();
+
+    let mut tiles: SparseGrid<Tile> = SparseGrid::new();
+    for (row_minus_1, line) in board_lines.iter().enumerate() {
+        let row_idx = row_minus_1 + 1; // rows and columns are 1-indexed
+        for (col_minus_1, c) in line.chars().enumerate() {
+            let point = Point2::new((col_minus_1 + 1) as isize, row_idx as isize);
+            match c {
+                '.' => {
+                    tiles.insert(point, Tile::Open);
+                }
+                '#' => {
+                    tiles.insert(point, Tile::Wall);
+                }
+                ' ' => (),
+                _ => {
+                    panic!("unknown character building map {}", c);
+                }
+            }
+        }
+    }
+    let board = Board { tiles };
+    let instructions = parse_instructions(&instruction_lines[0]);
+
+    (board, instructions)
+}
+
+/// Numbers of steps with an `L` or `R` turn between each
+fn check_path(scanner: &mut Scanner) -> Result<(), Mismatch> {
+    scanner.unsigned()?;
+    while !scanner.is_at_end() {
+        scanner.one_of("LR")?;
+        scanner.unsigned()?;
+    }
+    Ok(())
+}
+
+/// Every row and every column of the board is one unbroken run of tiles, so
+/// that walking off an edge always has somewhere to wrap to
+fn check_board_shape(board: &[(usize, &str)]) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut open_cols: HashSet<usize> = HashSet::new();
+    let mut closed_cols: HashSet<usize> = HashSet::new();
+    for (line_no, line) in board {
+        let line: &str = line.trim_end();
+        let tile_cols: HashSet<usize> = line
+            .char_indices()
+            .filter(|(_, c)| *c != ' ')
+            .map(|(col, _)| col)
+            .collect();
+        let first_tile = line.len() - line.trim_start().len();
+        if let Some(gap) = line[first_tile..].find(' ') {
+            diagnostics.push(Diagnostic::new(
+                *line_no,
+                first_tile + gap + 1,
+                "a tile",
+                line,
+            ));
+        }
+        let mut cols: Vec<usize> = tile_cols.intersection(&closed_cols).copied().collect();
+        cols.sort();
+        for col in cols {
+            diagnostics.push(Diagnostic::new(
+                *line_no,
+                col + 1,
+                "a gap, since the column above has ended",
+                line,
+            ));
+            closed_cols.remove(&col);
+        }
+        closed_cols.extend(open_cols.difference(&tile_cols));
+        open_cols = tile_cols;
+    }
+    diagnostics
+}
+
+/// The board folds into a cube: it is made of six square faces, each row
+/// starting and ending on a face edge, and as many rows as fill whole faces
+fn check_cube_faces(board: &[(usize, &str)]) -> Vec<Diagnostic> {
+    let rows: Vec<(usize, usize)> = board
+        .iter()
+        .map(|(_, line)| {
+            let line = line.trim_end();
+            let first_tile = line.len() - line.trim_start().len();
+            (first_tile, line.len() - first_tile)
+        })
+        .collect();
+    let n_tiles: usize = rows.iter().map(|(_, n_row_tiles)| n_row_tiles).sum();
+    let side = ((n_tiles / 6) as f64).sqrt().round() as usize;
+    let (last_line_no, _) = board[board.len() - 1];
+    if side == 0 || 6 * side * side != n_tiles || !rows.len().is_multiple_of(side) {
+        let expected = "a board of six square faces";
+        return vec![Diagnostic::new(last_line_no + 1, 1, expected, "")];
+    }
+    let expected = format!("faces of {} by {} tiles", side, side);
+    board
+        .iter()
+        .zip(rows)
+        .filter(|(_, (first_tile, n_row_tiles))| {
+            !first_tile.is_multiple_of(side) || !n_row_tiles.is_multiple_of(side)
+        })
+        .map(|((line_no, line), (first_tile, _))| {
+            Diagnostic::new(*line_no, first_tile + 1, expected.clone(), *line)
+        })
+        .take(1)
+        .collect()
+}
+
+/// The board of open tiles and walls, a blank line, then the path
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_not_empty(input);
+    let sections = paragraphs(input);
+    let [board, path] = sections.as_slice() else {
+        let line = sections
+            .get(2)
+            .map_or(input.lines().count() + 1, |section| section[0].0);
+        diagnostics.push(Diagnostic::new(
+            line,
+            1,
+            "a board and a path separated by one blank line",
+            "",
+        ));
+        return diagnostics;
+    };
+
+    for (line_no, line) in board {
+        if let Err(mismatch) = Scanner::new(line).all_of(" .#") {
+            diagnostics.push(mismatch.at_line(*line_no, line));
+        }
+    }
+    if diagnostics.is_empty() {
+        diagnostics = check_board_shape(board);
+    }
+    if diagnostics.is_empty() {
+        diagnostics = check_cube_faces(board);
+    }
+    let (line_no, line) = path[0];
+    if let Err(mismatch) = check_path(&mut Scanner::new(line)) {
+        diagnostics.push(mismatch.at_line(line_no, line));
+    }
+    if let Some((line_no, line)) = path.get(1) {
+        diagnostics.push(Diagnostic::new(
+            *line_no,
+            1,
+            "a path on a single line",
+            *line,
+        ));
+    }
+    diagnostics
+}
+
+pub fn part_1(board: &Board, instructions: &[Instruction]) -> isize {
+    let mut mover = Mover::new(board);
+    for instruction in instructions {
+        mover.execute_instruction_part_1(board, *instruction);
+    }
+
+    let final_row = mover.position.y;
+    let final_col = mover.position.x;
+    let facing = facing(mover.direction);
+
+    debug!("row: {}, col: {}, facing: {}", final_row, final_col, facing);
+
+    1_000 * final_row + 4 * final_col + facing
+}
+
+pub fn part_2(board: &Board, instructions: &[Instruction]) -> isize {
+    let mut mover = Mover::new(board);
+    for instruction in instructions {
+        mover.execute_instruction_part_2(board, *instruction);
+    }
+
+    let final_row = mover.position.y;
+    let final_col = mover.position.x;
+    let facing = facing(mover.direction);
+
+    debug!("row: {}, col: {}, facing: {}", final_row, final_col, facing);
+
+    1_000 * final_row + 4 * final_col + facing
+}
+
+#[derive(Default)]
+pub struct Day22;
+
+impl Params for Day22 {}
+
+impl Solution for Day22 {
+    type Input = (Board, Vec<Instruction>);
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> isize {
+        let (board, instructions) = input;
+        part_1(board, instructions)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> isize {
+        let (board, instructions) = input;
+        part_2(board, instructions)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(6032)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day22);
+
+    #[test]
+    fn test_parse_input() {
+        parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        parse_input(
+            AocBufReader::from_path("inputs/part_1.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        assert_eq!(validate(&input), vec![]);
+        assert_eq!(
+            validate("  ..#\n  .x.\n\n10R5L\n"),
+            vec![
+                Diagnostic::new(2, 4, "one of ' .#'", "  .x."),
+                Diagnostic::new(4, 6, "a number", "10R5L"),
+            ]
+        );
+        assert_eq!(
+            validate("  ..#\n.# ..\n.\n  .\n\n10R5L1\n"),
+            vec![
+                Diagnostic::new(2, 3, "a tile", ".# .."),
+                Diagnostic::new(4, 3, "a gap, since the column above has ended", "  ."),
+            ]
+        );
+        assert_eq!(
+            validate("..\n..\n\n10R5L1\n"),
+            vec![Diagnostic::new(3, 1, "a board of six square faces", "")]
+        );
+    }
+}
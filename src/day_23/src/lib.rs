@@ -0,0 +1,371 @@
+use shared::animate::{Animated, Colour, Glyph, Render, Scene};
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::geom::Point2;
+use shared::grid::SparseGrid;
+use shared::params::Params;
+use shared::sim::{Driver, Simulation};
+use shared::solution::Solution;
+use shared::validate::{check_grid, check_not_empty, ensure_valid, numbered_lines, Diagnostic};
+
+#[derive(Clone)]
+enum CardinalDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl CardinalDirection {
+    /// The step taken to move this way, as `(row, col)` offsets
+    fn step(&self) -> (isize, isize) {
+        match self {
+            CardinalDirection::North => (-1, 0),
+            CardinalDirection::East => (0, 1),
+            CardinalDirection::South => (1, 0),
+            CardinalDirection::West => (0, -1),
+        }
+    }
+
+    /// The three neighbours that have to be free to move this way
+    fn looks_at(&self) -> [(isize, isize); 3] {
+        let (drow, dcol) = self.step();
+        if drow == 0 {
+            [(-1, dcol), (0, dcol), (1, dcol)]
+        } else {
+            [(drow, -1), (drow, 0), (drow, 1)]
+        }
+    }
+}
+
+fn offset(point: Point2<isize>, (drow, dcol): (isize, isize)) -> Point2<isize> {
+    Point2::new(point.x + dcol, point.y + drow)
+}
+
+#[derive(Clone)]
+struct Elf {
+    position: Point2<isize>,
+    proposed_move: Point2<isize>,
+}
+
+impl Elf {
+    fn new(row: isize, col: isize) -> Elf {
+        Elf {
+            position: Point2::new(col, row),
+            proposed_move: Point2::new(col, row),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Elves {
+    elves: Vec<Elf>,
+    _direction: CardinalDirection,
+}
+
+impl Elves {
+    fn new(elves: Vec<Elf>) -> Elves {
+        Elves {
+            elves,
+            _direction: CardinalDirection::North,
+        }
+    }
+
+    fn get_candidate_directions(&self) -> Vec<CardinalDirection> {
+        match self._direction {
+            CardinalDirection::North => {
+                vec![
+                    CardinalDirection::North,
+                    CardinalDirection::South,
+                    CardinalDirection::West,
+                    CardinalDirection::East,
+                ]
+            }
+            CardinalDirection::South => {
+                vec![
+                    CardinalDirection::South,
+                    CardinalDirection::West,
+                    CardinalDirection::East,
+                    CardinalDirection::North,
+                ]
+            }
+            CardinalDirection::West => {
+                vec![
+                    CardinalDirection::West,
+                    CardinalDirection::East,
+                    CardinalDirection::North,
+                    CardinalDirection::South,
+                ]
+            }
+            CardinalDirection::East => {
+                vec![
+                    CardinalDirection::East,
+                    CardinalDirection::North,
+                    CardinalDirection::South,
+                    CardinalDirection::West,
+                ]
+            }
+        }
+    }
+
+    fn cycle_direction(&mut self) {
+        match self._direction {
+            CardinalDirection::North => self._direction = CardinalDirection::South,
+            CardinalDirection::South => self._direction = CardinalDirection::West,
+            CardinalDirection::West => self._direction = CardinalDirection::East,
+            CardinalDirection::East => self._direction = CardinalDirection::North,
+        }
+    }
+
+    /// The index of the elf at each position
+    fn elf_positions(&self) -> SparseGrid<usize> {
+        self.elves
+            .iter()
+            .enumerate()
+            .map(|(elf_idx, elf)| (elf.position, elf_idx))
+            .collect()
+    }
+
+    fn has_neighbors(
+        position: Point2<isize>,
+        direction: &CardinalDirection,
+        elf_positions: &SparseGrid<usize>,
+    ) -> bool {
+        direction
+            .looks_at()
+            .into_iter()
+            .any(|step| elf_positions.contains(&offset(position, step)))
+    }
+
+    fn plan_moves(&mut self) {
+        let candidate_directions = self.get_candidate_directions();
+        self.cycle_direction();
+
+        let elf_positions = self.elf_positions();
+        for elf in self.elves.iter_mut() {
+            let blocked: Vec<bool> = candidate_directions
+                .iter()
+                .map(|direction| Elves::has_neighbors(elf.position, direction, &elf_positions))
+                .collect();
+
+            // an elf with no neighbours at all stays put, as does one with
+            // neighbours in every direction
+            elf.proposed_move = elf.position;
+            if blocked.iter().any(|is_blocked| *is_blocked) {
+                if let Some(direction) = candidate_directions
+                    .iter()
+                    .zip(blocked)
+                    .find(|(_, is_blocked)| !is_blocked)
+                    .map(|(direction, _)| direction)
+                {
+                    elf.proposed_move = offset(elf.position, direction.step());
+                }
+            }
+        }
+    }
+
+    fn execute_moves(&mut self) -> bool {
+        let mut n_elves_planning_to_move_to: SparseGrid<usize> = SparseGrid::new();
+        for elf in self.elves.iter() {
+            match n_elves_planning_to_move_to.get_mut(&elf.proposed_move) {
+                Some(counter) => *counter += 1,
+                None => {
+                    n_elves_planning_to_move_to.insert(elf.proposed_move, 1);
+                }
+            }
+        }
+
+        let mut elves_moved = false;
+        for elf in self.elves.iter_mut() {
+            if n_elves_planning_to_move_to.get(&elf.proposed_move) == Some(&1) {
+                if elf.position != elf.proposed_move {
+                    elves_moved = true;
+                }
+                elf.position = elf.proposed_move;
+            }
+        }
+
+        elves_moved
+    }
+
+    fn part_1_count(&self) -> isize {
+        let elf_positions = self.elf_positions();
+        let (min, max) = elf_positions.bounds().unwrap();
+        (max.y - min.y + 1) * (max.x - min.x + 1) - elf_positions.len() as isize
+    }
+}
+
+/// What `aoc animate` draws for an elf
+pub enum ElfCell {
+    Elf,
+    /// Stayed put in the last round because another elf wanted the same spot
+    Blocked,
+}
+
+impl Render for Elves {
+    type Cell = ElfCell;
+
+    fn cells(&self) -> Vec<(Point2<isize>, ElfCell)> {
+        self.elves
+            .iter()
+            .map(|elf| {
+                if elf.proposed_move == elf.position {
+                    (elf.position, ElfCell::Elf)
+                } else {
+                    (elf.position, ElfCell::Blocked)
+                }
+            })
+            .collect()
+    }
+}
+
+fn elf_glyph(cell: &ElfCell) -> Glyph {
+    match cell {
+        ElfCell::Elf => Glyph::coloured('#', Colour::Green),
+        ElfCell::Blocked => Glyph::coloured('#', Colour::Red),
+    }
+}
+
+/// Each step is one round of planning and moving
+impl Simulation for Elves {
+    type Snapshot = Elves;
+
+    fn step(&mut self) -> bool {
+        self.plan_moves();
+        self.execute_moves()
+    }
+
+    fn snapshot(&self) -> Elves {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Elves) {
+        *self = snapshot.clone();
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Elves {
+    let mut elves: Vec<Elf> = Vec::new();
+    for (row_idx, row) in reader.enumerate() {
+        for (col_idx, c) in row.chars().enumerate() {
+            match c {
+                '#' => elves.push(Elf::new(row_idx as isize, col_idx as isize)),
+                '.' => (),
+                _ => {
+                    panic!("unexpected character")
+                }
+            }
+        }
+    }
+
+    Elves::new(elves)
+}
+
+/// A rectangle of empty ground `.` and elves `#`
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let mut diagnostics = check_not_empty(input);
+    diagnostics.extend(check_grid(&lines, ".#"));
+    diagnostics
+}
+
+pub fn part_1(elves: Elves) -> isize {
+    let mut driver = Driver::new(elves);
+    driver.run(10);
+    driver.sim().part_1_count()
+}
+
+pub fn part_2(elves: Elves) -> usize {
+    Driver::new(elves).run_until_fixed_point()
+}
+
+#[derive(Default)]
+pub struct Day23;
+
+impl Params for Day23 {}
+
+impl Solution for Day23 {
+    type Input = Elves;
+    type Answer1 = isize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> isize {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(110).part_2(20)]
+    }
+
+    fn animation(&self, input: &Self::Input, _part: usize) -> Option<Box<dyn Scene>> {
+        Some(Box::new(Animated::new(input.clone(), elf_glyph)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day23);
+
+    #[test]
+    fn test_parse_input() {
+        parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        parse_input(
+            AocBufReader::from_path("inputs/part_1.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+    }
+
+    #[test]
+    fn test_rounds() {
+        let small = [".....", "..##.", "..#..", ".....", "..##.", "....."];
+        let elves = parse_input(small.iter().map(|row| row.to_string()));
+        let mut driver = Driver::recording(elves);
+        // nobody moves in the fourth round
+        assert_eq!(driver.run_until_fixed_point(), 4);
+
+        driver.seek(1);
+        assert_eq!(
+            driver.sim().elf_positions().render('.', |_| '#'),
+            "##\n..\n#.\n.#\n#."
+        );
+    }
+
+    #[test]
+    fn test_animation() {
+        let small = [".....", "..##.", "..#..", ".....", "..##.", "....."];
+        let mut scene = Day23
+            .animation(&Day23.parse(&small.join("\n")).unwrap(), 2)
+            .unwrap();
+        scene.step();
+        // two elves want the same spot in the first round
+        let blocked: Vec<Point2<isize>> = scene
+            .glyphs()
+            .into_iter()
+            .filter(|(_, glyph)| glyph.colour == Some(Colour::Red))
+            .map(|(point, _)| point)
+            .collect();
+        assert_eq!(blocked, vec![Point2::new(2, 2), Point2::new(2, 4)]);
+        assert_eq!(scene.focus(), Some((Point2::new(2, 0), Point2::new(3, 4))));
+    }
+}
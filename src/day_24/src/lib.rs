@@ -0,0 +1,651 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use shared::animate::{Animated, Colour, Glyph, Render, Scene};
+use shared::cycle;
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::geom::Point2;
+use shared::grid::Grid;
+use shared::info;
+use shared::params::Params;
+use shared::search::bfs;
+use shared::sim::Simulation;
+use shared::solution::{Extra, Solution};
+use shared::validate::{check_grid, check_not_empty, ensure_valid, numbered_lines, Diagnostic};
+
+/// Where the expedition is, and the minute within the blizzard period: the
+/// valley looks the same every period, so later visits are no different
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct Node {
+    position: Point2<usize>,
+    phase: usize,
+}
+
+#[derive(Clone)]
+pub struct BlizzardMap {
+    start: Point2<usize>,
+    end: Point2<usize>,
+    /// The whole map, with the blizzards moving inside the walls
+    walls: Grid<bool>,
+    blizzards_north: HashSet<Point2<usize>>,
+    blizzards_east: HashSet<Point2<usize>>,
+    blizzards_south: HashSet<Point2<usize>>,
+    blizzards_west: HashSet<Point2<usize>>,
+    _t: usize,
+    _open_spaces_by_t: HashMap<usize, Grid<bool>>,
+}
+
+impl BlizzardMap {
+    fn new(
+        start: Point2<usize>,
+        end: Point2<usize>,
+        walls: Grid<bool>,
+        blizzards_north: HashSet<Point2<usize>>,
+        blizzards_east: HashSet<Point2<usize>>,
+        blizzards_south: HashSet<Point2<usize>>,
+        blizzards_west: HashSet<Point2<usize>>,
+    ) -> BlizzardMap {
+        let mut blizzard_map = BlizzardMap {
+            start,
+            end,
+            walls,
+            blizzards_north,
+            blizzards_east,
+            blizzards_south,
+            blizzards_west,
+            _t: 0,
+            _open_spaces_by_t: HashMap::new(),
+        };
+        blizzard_map
+            ._open_spaces_by_t
+            .insert(0, blizzard_map.open_spaces());
+        blizzard_map
+    }
+
+    /// The last row inside the walls
+    fn max_row(&self) -> usize {
+        self.walls.n_rows() - 2
+    }
+
+    /// The last column inside the walls
+    fn max_col(&self) -> usize {
+        self.walls.n_cols() - 2
+    }
+
+    fn n_spaces(&self) -> usize {
+        self.max_row() * self.max_col()
+    }
+
+    fn blizzard_spaces(&self) -> HashSet<Point2<usize>> {
+        [
+            &self.blizzards_north,
+            &self.blizzards_east,
+            &self.blizzards_south,
+            &self.blizzards_west,
+        ]
+        .into_iter()
+        .flatten()
+        .copied()
+        .collect()
+    }
+
+    /// Where the expedition can be right now: anywhere but a wall or a blizzard
+    fn open_spaces(&self) -> Grid<bool> {
+        let mut open_spaces = self.walls.map(|is_wall| !is_wall);
+        for blizzard in self.blizzard_spaces() {
+            open_spaces[blizzard] = false;
+        }
+        open_spaces
+    }
+
+    /// Every blizzard, by direction, in a fixed order
+    fn blizzards_key(&self) -> Vec<Vec<Point2<usize>>> {
+        [
+            &self.blizzards_north,
+            &self.blizzards_east,
+            &self.blizzards_south,
+            &self.blizzards_west,
+        ]
+        .into_iter()
+        .map(|blizzards| {
+            let mut points: Vec<Point2<usize>> = blizzards.iter().copied().collect();
+            points.sort();
+            points
+        })
+        .collect()
+    }
+
+    fn calculate_next(&mut self) {
+        self._t += 1;
+        let (max_row, max_col) = (self.max_row(), self.max_col());
+
+        self.blizzards_north = self
+            .blizzards_north
+            .iter()
+            .map(|point| {
+                let row = if point.y == 1 { max_row } else { point.y - 1 };
+                Point2::new(point.x, row)
+            })
+            .collect();
+
+        self.blizzards_east = self
+            .blizzards_east
+            .iter()
+            .map(|point| {
+                let col = if point.x == max_col { 1 } else { point.x + 1 };
+                Point2::new(col, point.y)
+            })
+            .collect();
+
+        self.blizzards_south = self
+            .blizzards_south
+            .iter()
+            .map(|point| {
+                let row = if point.y == max_row { 1 } else { point.y + 1 };
+                Point2::new(point.x, row)
+            })
+            .collect();
+
+        self.blizzards_west = self
+            .blizzards_west
+            .iter()
+            .map(|point| {
+                let col = if point.x == 1 { max_col } else { point.x - 1 };
+                Point2::new(col, point.y)
+            })
+            .collect();
+
+        let open_spaces = self.open_spaces();
+        self._open_spaces_by_t.insert(self._t, open_spaces);
+    }
+
+    fn get_open_spaces_at_time_t(&mut self, t: usize) -> &Grid<bool> {
+        while self._t < t {
+            self.step();
+        }
+
+        self._open_spaces_by_t.get(&t).unwrap()
+    }
+
+    fn get_neighbor_nodes(&mut self, node: Node, period: usize) -> Vec<Node> {
+        let next_phase = (node.phase + 1) % period;
+        let open_spaces = self.get_open_spaces_at_time_t(next_phase);
+
+        // waiting is staying put
+        [node.position]
+            .into_iter()
+            .chain(open_spaces.neighbors_4(node.position))
+            .filter(|position| open_spaces[*position])
+            .map(|position| Node {
+                position,
+                phase: next_phase,
+            })
+            .collect()
+    }
+}
+
+/// What `aoc animate` draws in the valley
+pub enum ValleyCell {
+    Wall,
+    Blizzard(char),
+    /// Several blizzards in the same spot
+    Blizzards(usize),
+}
+
+impl Render for BlizzardMap {
+    type Cell = ValleyCell;
+
+    fn cells(&self) -> Vec<(Point2<isize>, ValleyCell)> {
+        let to_isize = |point: Point2<usize>| Point2::new(point.x as isize, point.y as isize);
+        let mut cells: Vec<(Point2<isize>, ValleyCell)> = self
+            .walls
+            .iter()
+            .filter(|(_, is_wall)| **is_wall)
+            .map(|(point, _)| (to_isize(point), ValleyCell::Wall))
+            .collect();
+
+        let mut blizzards: HashMap<Point2<usize>, Vec<char>> = HashMap::new();
+        for (points, direction) in [
+            (&self.blizzards_north, '^'),
+            (&self.blizzards_east, '>'),
+            (&self.blizzards_south, 'v'),
+            (&self.blizzards_west, '<'),
+        ] {
+            for point in points {
+                blizzards.entry(*point).or_default().push(direction);
+            }
+        }
+        cells.extend(
+            blizzards
+                .into_iter()
+                .map(|(point, directions)| match directions[..] {
+                    [direction] => (to_isize(point), ValleyCell::Blizzard(direction)),
+                    _ => (to_isize(point), ValleyCell::Blizzards(directions.len())),
+                }),
+        );
+        cells
+    }
+}
+
+fn valley_glyph(cell: &ValleyCell) -> Glyph {
+    match cell {
+        ValleyCell::Wall => Glyph::coloured('#', Colour::Grey),
+        ValleyCell::Blizzard(direction) => Glyph::coloured(*direction, Colour::Blue),
+        ValleyCell::Blizzards(n) => {
+            Glyph::coloured(char::from_digit(*n as u32, 10).unwrap_or('*'), Colour::Cyan)
+        }
+    }
+}
+
+/// Where the blizzards are at time `t`
+pub struct Blizzards {
+    t: usize,
+    north: HashSet<Point2<usize>>,
+    east: HashSet<Point2<usize>>,
+    south: HashSet<Point2<usize>>,
+    west: HashSet<Point2<usize>>,
+}
+
+/// Each step moves the blizzards on by one minute. Open spaces stay cached
+/// by time, so restoring an earlier time keeps them.
+impl Simulation for BlizzardMap {
+    type Snapshot = Blizzards;
+
+    fn step(&mut self) -> bool {
+        self.calculate_next();
+        true
+    }
+
+    fn snapshot(&self) -> Blizzards {
+        Blizzards {
+            t: self._t,
+            north: self.blizzards_north.clone(),
+            east: self.blizzards_east.clone(),
+            south: self.blizzards_south.clone(),
+            west: self.blizzards_west.clone(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: &Blizzards) {
+        self._t = snapshot.t;
+        self.blizzards_north = snapshot.north.clone();
+        self.blizzards_east = snapshot.east.clone();
+        self.blizzards_south = snapshot.south.clone();
+        self.blizzards_west = snapshot.west.clone();
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> BlizzardMap {
+    let mut blizzards_north: HashSet<Point2<usize>> = HashSet::new();
+    let mut blizzards_east: HashSet<Point2<usize>> = HashSet::new();
+    let mut blizzards_south: HashSet<Point2<usize>> = HashSet::new();
+    let mut blizzards_west: HashSet<Point2<usize>> = HashSet::new();
+
+    let lines: Vec<String> = reader.collect();
+    let walls = Grid::parse(&lines, |c| Some(c == '#')).unwrap();
+    let mut start = Point2::new(0, 0);
+    let mut end = Point2::new(0, 0);
+    for (row, line) in lines.iter().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            let point = Point2::new(col, row);
+            match c {
+                '#' => (),
+                '.' => {
+                    if row == 0 {
+                        start = point;
+                    } else if row == lines.len() - 1 {
+                        end = point;
+                    }
+                }
+                '^' => {
+                    blizzards_north.insert(point);
+                }
+                '>' => {
+                    blizzards_east.insert(point);
+                }
+                'v' => {
+                    blizzards_south.insert(point);
+                }
+                '<' => {
+                    blizzards_west.insert(point);
+                }
+                _ => {
+                    panic!("unexepcted char {}", c);
+                }
+            }
+        }
+    }
+
+    BlizzardMap::new(
+        start,
+        end,
+        walls,
+        blizzards_north,
+        blizzards_east,
+        blizzards_south,
+        blizzards_west,
+    )
+}
+
+/// The top and bottom rows are wall but for one opening each, away from the
+/// corners, and every row in between is ground and blizzards inside a wall
+/// on either side
+fn check_valley(lines: &[(usize, &str)]) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let (Some((first_no, first)), Some((last_no, last))) = (lines.first(), lines.last()) else {
+        return diagnostics;
+    };
+    if lines.len() < 3 {
+        diagnostics.push(Diagnostic::new(
+            *last_no,
+            1,
+            "a valley between the top and bottom rows",
+            *last,
+        ));
+        return diagnostics;
+    }
+    for (line_no, line) in [(first_no, first), (last_no, last)] {
+        let n_cols = line.chars().count();
+        let openings: Vec<usize> = line
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| *c != '#')
+            .map(|(col, _)| col)
+            .collect();
+        match openings[..] {
+            [col] if col > 0 && col + 1 < n_cols && line.chars().nth(col) == Some('.') => (),
+            [] => diagnostics.push(Diagnostic::new(*line_no, 1, "one opening", *line)),
+            [col] => diagnostics.push(Diagnostic::new(
+                *line_no,
+                col + 1,
+                "wall, with an opening into the valley",
+                *line,
+            )),
+            [_, col, ..] => diagnostics.push(Diagnostic::new(
+                *line_no,
+                col + 1,
+                "a single opening",
+                *line,
+            )),
+        }
+    }
+    for (line_no, line) in &lines[1..lines.len() - 1] {
+        let n_cols = line.chars().count();
+        for (col, c) in line.chars().enumerate() {
+            let is_side = col == 0 || col + 1 == n_cols;
+            if is_side != (c == '#') {
+                let expected = if is_side {
+                    "a wall"
+                } else {
+                    "ground or a blizzard"
+                };
+                diagnostics.push(Diagnostic::new(*line_no, col + 1, expected, *line));
+            }
+        }
+    }
+    diagnostics
+}
+
+/// A walled rectangle of ground and blizzards
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let mut diagnostics = check_not_empty(input);
+    diagnostics.extend(check_grid(&lines, "#.<>^v"));
+    if diagnostics.is_empty() {
+        diagnostics.extend(check_valley(&lines));
+    }
+    diagnostics
+}
+
+/// The earliest time at which the expedition, setting off from `start_position`
+/// at `t`, can reach `end_position`. The blizzards only need simulating for
+/// one `period`.
+fn shortest_path(
+    blizzard_map: &mut BlizzardMap,
+    period: usize,
+    start_position: Point2<usize>,
+    t: usize,
+    end_position: Point2<usize>,
+) -> Option<usize> {
+    let start_node = Node {
+        position: start_position,
+        phase: t % period,
+    };
+    let path = bfs(
+        [start_node],
+        |node| blizzard_map.get_neighbor_nodes(node.clone(), period),
+        |node| node.position == end_position,
+    )?;
+    Some(t + path.cost)
+}
+
+/// The time at which each of the first `n_legs` legs finishes, and the
+/// blizzard period the search went by. Stops short at a leg the blizzards
+/// block for good.
+fn expedition(mut blizzard_map: BlizzardMap, n_legs: usize) -> (Vec<usize>, usize) {
+    let period = blizzard_period(blizzard_map.clone());
+    info!("blizzard_period: {}", period);
+    let mut legs: Vec<usize> = Vec::new();
+
+    let mut t: usize = 0;
+    let mut from = blizzard_map.start;
+    let mut to = blizzard_map.end;
+    for _ in 0..n_legs {
+        let Some(arrival) = shortest_path(&mut blizzard_map, period, from, t, to) else {
+            info!("leg_{}: blocked", legs.len() + 1);
+            break;
+        };
+        t = arrival;
+        info!("leg_{}: {}", legs.len() + 1, t);
+        legs.push(t);
+        (from, to) = (to, from);
+    }
+
+    (legs, period)
+}
+
+/// The expedition goes from the start to the end, back to the start for the
+/// forgotten snacks, and then to the end again. Returns the time at which
+/// each of the first `n_legs` legs finishes, or fewer if the way is blocked.
+pub fn expedition_legs(blizzard_map: BlizzardMap, n_legs: usize) -> Vec<usize> {
+    expedition(blizzard_map, n_legs).0
+}
+
+/// How many minutes it takes for the blizzards to be back where they started
+pub fn blizzard_period(mut blizzard_map: BlizzardMap) -> usize {
+    let n_spaces = blizzard_map.n_spaces();
+    cycle::find_cycle(
+        &mut blizzard_map,
+        BlizzardMap::blizzards_key,
+        |_| 0,
+        n_spaces,
+    )
+    .unwrap()
+    .period
+}
+
+/// When the expedition finishes its last leg, if the blizzards ever let it
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Arrival {
+    At(usize),
+    Blocked,
+}
+
+impl fmt::Display for Arrival {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arrival::At(t) => write!(f, "{}", t),
+            Arrival::Blocked => write!(f, "blocked: no way through the blizzards"),
+        }
+    }
+}
+
+/// When the last of `n_legs` legs finishes
+fn arrival(legs: &[usize], n_legs: usize) -> Arrival {
+    legs.get(n_legs - 1)
+        .map_or(Arrival::Blocked, |t| Arrival::At(*t))
+}
+
+/// The time at which the last of `n_legs` legs finishes, reporting each leg
+/// and the blizzard period
+fn expedition_with_extras(blizzard_map: BlizzardMap, n_legs: usize) -> (Arrival, Vec<Extra>) {
+    let (legs, period) = expedition(blizzard_map, n_legs);
+    let mut extras: Vec<Extra> = legs
+        .iter()
+        .zip(["leg_1", "leg_2", "leg_3"])
+        .map(|(t, name)| Extra::new(name, t))
+        .collect();
+    extras.push(Extra::new("blizzard_period", period));
+    (arrival(&legs, n_legs), extras)
+}
+
+pub fn part_1(blizzard_map: BlizzardMap) -> Arrival {
+    arrival(&expedition_legs(blizzard_map, 1), 1)
+}
+
+pub fn part_2(blizzard_map: BlizzardMap) -> Arrival {
+    arrival(&expedition_legs(blizzard_map, 3), 3)
+}
+
+#[derive(Default)]
+pub struct Day24;
+
+impl Params for Day24 {}
+
+impl Solution for Day24 {
+    type Input = BlizzardMap;
+    type Answer1 = Arrival;
+    type Answer2 = Arrival;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Arrival {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Arrival {
+        part_2(input.clone())
+    }
+
+    fn part_1_with_extras(&self, input: &Self::Input) -> (Arrival, Vec<Extra>) {
+        expedition_with_extras(input.clone(), 1)
+    }
+
+    fn part_2_with_extras(&self, input: &Self::Input) -> (Arrival, Vec<Extra>) {
+        expedition_with_extras(input.clone(), 3)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(18).part_2(54)]
+    }
+
+    fn animation(&self, input: &Self::Input, _part: usize) -> Option<Box<dyn Scene>> {
+        Some(Box::new(Animated::new(input.clone(), valley_glyph)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::animate::{render_frame, Viewport};
+    use shared::input::AocBufReader;
+    use shared::sim::Driver;
+
+    shared::example_tests!(Day24);
+
+    #[test]
+    fn test_parse_input() {
+        parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        parse_input(
+            AocBufReader::from_path("inputs/part_1.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        assert_eq!(validate(&input), vec![]);
+
+        let diagnostics = validate("#.######\n######.#\n");
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 1));
+        assert!(Day24.parse("#.######\n######.#\n").is_err());
+
+        // an extra wall row across the valley
+        let walled = input.replacen("#<^v^^>#", "########", 1);
+        let diagnostics = validate(&walled);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (5, 2));
+        assert_eq!(validate("#.#\n#.#\n#..\n")[0].column, 3);
+    }
+
+    #[test]
+    fn test_blocked() {
+        // blizzards fill the column below the start at every minute
+        let input = "#.###\n#v..#\n#v..#\n###.#\n";
+        let blizzard_map = Day24.parse(input).unwrap();
+        assert_eq!(part_1(blizzard_map), Arrival::Blocked);
+    }
+
+    #[test]
+    fn test_blizzards() {
+        let blizzard_map = parse_input(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        );
+        let initial = blizzard_map.blizzard_spaces();
+        let mut driver = Driver::new(blizzard_map);
+        // the 6 by 4 valley repeats every 12 minutes
+        assert_eq!(blizzard_period(driver.sim().clone()), 12);
+        driver.run(12);
+        assert_eq!(driver.sim().blizzard_spaces(), initial);
+
+        driver.seek(1);
+        assert_ne!(driver.sim().blizzard_spaces(), initial);
+        assert_eq!(driver.sim()._t, 1);
+
+        // the search never simulates past one period
+        let mut blizzard_map = driver.sim().clone();
+        let (start, end) = (blizzard_map.start, blizzard_map.end);
+        assert_eq!(
+            shortest_path(&mut blizzard_map, 12, start, 0, end),
+            Some(18)
+        );
+        assert!(blizzard_map._t < 12);
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        let mut scene = Day24.animation(&Day24.parse(&input).unwrap(), 1).unwrap();
+        let mut viewport = Viewport::new(6, 8);
+        viewport.follow(scene.focus().unwrap());
+        let plain = |frame: String| {
+            ["\x1b[34m", "\x1b[36m", "\x1b[90m", "\x1b[0m"]
+                .iter()
+                .fold(frame, |frame, code| frame.replace(code, ""))
+        };
+        let frame = render_frame(&scene.glyphs(), &viewport, Glyph::new('.'));
+        assert_eq!(plain(frame), input.trim_end());
+
+        scene.step();
+        let frame = render_frame(&scene.glyphs(), &viewport, Glyph::new('.'));
+        assert_eq!(
+            plain(frame),
+            ["#.######", "#.>3.<.#", "#<..<<.#", "#>2.22.#", "#>v..^<#", "######.#",].join("\n")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+fn snafu_to_decimal(s: &str) -> usize {
+    s.chars()
+        .rev()
+        .enumerate()
+        .map(|(idx, c)| match c {
+            '2' => 2 * 5isize.pow(idx.try_into().unwrap()),
+            '1' => 5isize.pow(idx.try_into().unwrap()),
+            '0' => 0,
+            '-' => -5isize.pow(idx.try_into().unwrap()),
+            '=' => -2 * 5isize.pow(idx.try_into().unwrap()),
+            _ => panic!("unexpected char {}", c),
+        })
+        .sum::<isize>() as usize
+}
+
+fn get_n_digits(val: usize) -> usize {
+    let mut n_digits: u32 = 1;
+    loop {
+        if 2 * 5usize.pow(n_digits) >= val {
+            break;
+        }
+        n_digits += 1;
+    }
+
+    // digits are 0 indexed and we want to return the length
+    n_digits as usize + 1
+}
+
+fn largest_value_of_length_n(n_digits: u32) -> isize {
+    (0u32..n_digits).map(|x| 2 * 5isize.pow(x)).sum()
+}
+
+fn decimal_to_snafu(val: usize) -> String {
+    let n_digits = get_n_digits(val);
+
+    let mut snafu: String = String::new();
+    let mut snafu_val: isize = 0;
+    for exponent in (0..n_digits).rev() {
+        let exponent: u32 = exponent.try_into().unwrap();
+        let largest_remainder: isize = if exponent == 0 {
+            0
+        } else {
+            largest_value_of_length_n(exponent)
+        };
+
+        let difference: isize = (val as isize) - snafu_val;
+        if difference >= 0 {
+            // need to make snafu bigger!
+            if difference > 5isize.pow(exponent) + largest_remainder {
+                snafu.push('2');
+                snafu_val += 2 * 5isize.pow(exponent);
+            } else if difference > largest_remainder {
+                snafu.push('1');
+                snafu_val += 5isize.pow(exponent);
+            } else {
+                snafu.push('0');
+            }
+        } else {
+            // need to make snafu smaller!
+            if difference < -5isize.pow(exponent) - largest_remainder {
+                snafu.push('=');
+                snafu_val -= 2 * 5isize.pow(exponent);
+            } else if difference < -largest_remainder {
+                snafu.push('-');
+                snafu_val -= 5isize.pow(exponent);
+            } else {
+                snafu.push('0');
+            }
+        }
+    }
+
+    snafu
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<String> {
+    reader.collect()
+}
+
+/// One SNAFU number per line
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        scanner.take_while("a SNAFU digit", |c| "=-012".contains(c))?;
+        scanner.end()
+    })
+}
+
+pub fn part_1(snafu_numbers: &[String]) -> String {
+    let running_value: usize = snafu_numbers
+        .iter()
+        .map(|snafu| snafu_to_decimal(snafu))
+        .sum();
+
+    decimal_to_snafu(running_value)
+}
+
+/// Day 25 only has one puzzle; the second star is awarded for finishing the other 49.
+pub fn part_2(_snafu_numbers: &[String]) -> String {
+    "Merry Christmas!".to_string()
+}
+
+#[derive(Default)]
+pub struct Day25;
+
+impl Params for Day25 {}
+
+impl Solution for Day25 {
+    type Input = Vec<String>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> String {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> String {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt")
+            .part_1("2=-1=0")
+            .part_2("Merry Christmas!")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::example_tests!(Day25);
+
+    #[test]
+    fn test_snafu_to_decimal() {
+        assert_eq!(snafu_to_decimal("1="), 3usize);
+        assert_eq!(snafu_to_decimal("1=11-2"), 2022usize);
+        assert_eq!(snafu_to_decimal("1121-1110-1=0"), 314159265usize);
+    }
+
+    #[test]
+    fn test_get_n_digits() {
+        assert_eq!(get_n_digits(1747), 6);
+        assert_eq!(get_n_digits(906), 5);
+        assert_eq!(get_n_digits(3), 2);
+    }
+
+    #[test]
+    fn test_decimal_to_snafu() {
+        assert_eq!(decimal_to_snafu(1747), "1=-0-2".to_string());
+        assert_eq!(decimal_to_snafu(314159265), "1121-1110-1=0".to_string());
+    }
+}
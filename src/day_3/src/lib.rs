@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use shared::conversion::char_to_usize;
+use shared::error::ParseError;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<String> {
+    reader.collect()
+}
+
+/// Rucksacks of letters that split into two equal compartments, in groups of three
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_lines(input, |scanner| {
+        let items = scanner.take_while("an item letter", |c| c.is_ascii_alphabetic())?;
+        scanner.end()?;
+        if !items.len().is_multiple_of(2) {
+            return Err(scanner.mismatch("an even number of items"));
+        }
+        let (first, second) = items.split_at(items.len() / 2);
+        if !first.chars().any(|item| second.contains(item)) {
+            return Err(scanner.mismatch("an item in both compartments"));
+        }
+        Ok(())
+    });
+
+    let n_lines = input.lines().count();
+    if !n_lines.is_multiple_of(3) {
+        diagnostics.push(Diagnostic::new(
+            n_lines + 1,
+            1,
+            "rucksacks in groups of three",
+            "",
+        ));
+    }
+    diagnostics
+}
+
+fn shared_character(items: &str) -> char {
+    let n_items: usize = items.len() / 2;
+
+    *items[..n_items]
+        .chars()
+        .collect::<HashSet<char>>()
+        .intersection(&items[n_items..].chars().collect::<HashSet<char>>())
+        .next()
+        .unwrap()
+}
+
+pub fn part_1(rucksacks: &[String]) -> usize {
+    rucksacks
+        .iter()
+        .map(|rucksack| char_to_usize(shared_character(rucksack)))
+        .sum()
+}
+
+pub fn part_2(rucksacks: &[String]) -> usize {
+    rucksacks
+        .chunks(3)
+        .map(|elves| {
+            char_to_usize(
+                *elves[0]
+                    .chars()
+                    .collect::<HashSet<char>>()
+                    .intersection(&elves[1].chars().collect::<HashSet<char>>())
+                    .copied()
+                    .collect::<HashSet<char>>()
+                    .intersection(&elves[2].chars().collect::<HashSet<char>>())
+                    .next()
+                    .unwrap(),
+            )
+        })
+        .sum()
+}
+
+#[derive(Default)]
+pub struct Day3;
+
+impl Params for Day3 {}
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_shared_character() {
+        assert_eq!(shared_character("vJrwpWtwJgWrhcsFMMfFFhFp"), 'p');
+        assert_eq!(shared_character("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"), 'L');
+    }
+
+    #[test]
+    fn test_char_to_usize() {
+        assert_eq!(char_to_usize('A'), 27);
+        assert_eq!(char_to_usize('b'), 2);
+    }
+}
@@ -0,0 +1,115 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::error::ParseError;
+use shared::interval::Interval;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic, Mismatch, Scanner};
+
+lazy_static! {
+    static ref INPUT_REGEX: Regex = Regex::new(r"^([0-9]*)-([0-9]*),([0-9]*)-([0-9]*)$").unwrap();
+}
+
+fn parse_single_line(string: String) -> (Interval<usize>, Interval<usize>) {
+    let captures = INPUT_REGEX.captures(&string).unwrap();
+
+    (
+        Interval::new(
+            captures.get(1).unwrap().as_str().parse::<usize>().unwrap(),
+            captures.get(2).unwrap().as_str().parse::<usize>().unwrap(),
+        ),
+        Interval::new(
+            captures.get(3).unwrap().as_str().parse::<usize>().unwrap(),
+            captures.get(4).unwrap().as_str().parse::<usize>().unwrap(),
+        ),
+    )
+}
+
+pub fn parse_input(
+    reader: impl Iterator<Item = String>,
+) -> Vec<(Interval<usize>, Interval<usize>)> {
+    reader
+        .into_iter()
+        .map(parse_single_line)
+        .collect::<Vec<(Interval<usize>, Interval<usize>)>>()
+}
+
+fn check_range(scanner: &mut Scanner) -> Result<(), Mismatch> {
+    let start_column = scanner.column();
+    let start = scanner.unsigned()?;
+    scanner.literal("-")?;
+    let end = scanner.unsigned()?;
+    if start > end {
+        return Err(Mismatch {
+            column: start_column,
+            expected: "a range that does not end before it starts".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// `a-b,c-d` section assignment pairs
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        check_range(scanner)?;
+        scanner.literal(",")?;
+        check_range(scanner)?;
+        scanner.end()
+    })
+}
+
+pub fn part_1(tuple_pairs: Vec<(Interval<usize>, Interval<usize>)>) -> usize {
+    tuple_pairs
+        .into_iter()
+        .filter(|(r1, r2)| r1.contains_interval(r2) || r2.contains_interval(r1))
+        .count()
+}
+
+pub fn part_2(tuple_pairs: Vec<(Interval<usize>, Interval<usize>)>) -> usize {
+    tuple_pairs
+        .into_iter()
+        .filter(|(r1, r2)| r1.overlaps(r2))
+        .count()
+}
+
+#[derive(Default)]
+pub struct Day4;
+
+impl Params for Day4 {}
+
+impl Solution for Day4 {
+    type Input = Vec<(Interval<usize>, Interval<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_line() {
+        assert_eq!(
+            parse_single_line("12-34,56-78".to_string()),
+            (Interval::new(12, 34), Interval::new(56, 78))
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::error::ParseError;
+use shared::input::{is_blank, LineGroups};
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_not_empty, ensure_valid, paragraphs, Diagnostic, Mismatch, Scanner};
+
+lazy_static! {
+    static ref INSTRUCTION_REGEX: Regex =
+        Regex::new(r"^move ([0-9]*) from ([0-9]*) to ([0-9]*)$").unwrap();
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Instruction {
+    source_stack_idx: usize,
+    destination_stack_idx: usize,
+    n_boxes: usize,
+}
+
+/// Trailing empty stacks may be missing from the line, since
+/// trailing whitespace is not always preserved in the input.
+fn parse_stack_line(line: String) -> Vec<Option<char>> {
+    // each stack takes up four characters, e.g. "[C] ", with the box label second
+    line.chars()
+        .skip(1)
+        .step_by(4)
+        .map(|central_char| match central_char {
+            ' ' => None,
+            _ => Some(central_char),
+        })
+        .collect()
+}
+
+fn parse_instruction(line: String) -> Instruction {
+    let captures = INSTRUCTION_REGEX.captures(&line).unwrap();
+    // stack 0 is left out of range rather than wrapping, for `check_moves`
+    Instruction {
+        source_stack_idx: captures[2].parse::<usize>().unwrap().wrapping_sub(1),
+        destination_stack_idx: captures[3].parse::<usize>().unwrap().wrapping_sub(1),
+        n_boxes: captures.get(1).unwrap().as_str().parse::<usize>().unwrap(),
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> (Vec<Vec<char>>, Vec<Instruction>) {
+    let (stack_lines, instruction_lines) = reader.split_at_blank();
+    let parsed_boxes: Vec<Vec<Option<char>>> =
+        stack_lines.into_iter().map(parse_stack_line).collect();
+
+    // the last line holds the stack numbers, so it is never truncated
+    let n_stacks: usize = parsed_boxes.last().unwrap().len();
+    let mut stacks: Vec<Vec<char>> = (0..n_stacks).map(|_| Vec::new()).collect();
+
+    let highest_stack_height: usize = parsed_boxes.len() - 1;
+    for row in parsed_boxes[..highest_stack_height].iter().rev() {
+        for (stack_idx, box_) in row.iter().enumerate() {
+            if let Some(char_) = box_ {
+                stacks[stack_idx].push(*char_);
+            }
+        }
+    }
+
+    let instructions: Vec<Instruction> = instruction_lines
+        .into_iter()
+        .map(parse_instruction)
+        .collect();
+
+    (stacks, instructions)
+}
+
+/// A row of crates, each either `[X]` or blank, separated by single spaces
+fn check_crate_row(scanner: &mut Scanner) -> Result<(), Mismatch> {
+    loop {
+        if !scanner.optional("   ") {
+            scanner.literal("[")?;
+            scanner.take_while("a crate letter", |c| c.is_ascii_uppercase())?;
+            scanner.literal("]")?;
+        }
+        if scanner.is_at_end() {
+            return Ok(());
+        }
+        scanner.literal(" ")?;
+    }
+}
+
+/// `move N from A to B`
+fn check_move(scanner: &mut Scanner) -> Result<(), Mismatch> {
+    scanner.literal("move ")?;
+    scanner.unsigned()?;
+    scanner.literal(" from ")?;
+    scanner.unsigned()?;
+    scanner.literal(" to ")?;
+    scanner.unsigned()?;
+    scanner.end()
+}
+
+/// Every move is between stacks that exist and takes at most as many crates
+/// as its stack holds. `first_line` is the line of the first move.
+fn check_moves(
+    stacks: &[Vec<char>],
+    instructions: &[Instruction],
+    first_line: usize,
+) -> Result<(), ParseError> {
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for (line, instruction) in (first_line..).zip(instructions) {
+        let n_stacks = heights.len();
+        if instruction.source_stack_idx >= n_stacks || instruction.destination_stack_idx >= n_stacks
+        {
+            let message = format!("expected stacks numbered 1 to {}", n_stacks);
+            return Err(ParseError::at_line(line, message));
+        }
+        let height = heights[instruction.source_stack_idx];
+        if instruction.n_boxes > height {
+            let message = format!(
+                "can't move {} crates from a stack of {}",
+                instruction.n_boxes, height
+            );
+            return Err(ParseError::at_line(line, message));
+        }
+        heights[instruction.source_stack_idx] -= instruction.n_boxes;
+        heights[instruction.destination_stack_idx] += instruction.n_boxes;
+    }
+    Ok(())
+}
+
+/// The drawing of the stacks with their numbers underneath, a blank line,
+/// then `move N from A to B` lines
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_not_empty(input);
+    let sections = paragraphs(input);
+    let (drawing, moves) = match sections.as_slice() {
+        [drawing, moves] => (drawing, moves),
+        _ => {
+            let line = sections
+                .get(2)
+                .map_or(input.lines().count() + 1, |section| section[0].0);
+            diagnostics.push(Diagnostic::new(
+                line,
+                1,
+                "a drawing and a list of moves separated by one blank line",
+                "",
+            ));
+            return diagnostics;
+        }
+    };
+
+    let (numbers, rows) = drawing.split_last().unwrap();
+    for (line_no, line) in rows {
+        if let Err(mismatch) = check_crate_row(&mut Scanner::new(line)) {
+            diagnostics.push(mismatch.at_line(*line_no, line));
+        }
+    }
+    if let Err(mismatch) = Scanner::new(numbers.1).all_of(" 0123456789") {
+        diagnostics.push(mismatch.at_line(numbers.0, numbers.1));
+    }
+    for (line_no, line) in moves {
+        if let Err(mismatch) = check_move(&mut Scanner::new(line)) {
+            diagnostics.push(mismatch.at_line(*line_no, line));
+        }
+    }
+    diagnostics
+}
+
+fn _part_1_execute_instruction(stacks: &mut [Vec<char>], instruction: Instruction) {
+    for _iter in 0..instruction.n_boxes {
+        if let Some(char_) = stacks[instruction.source_stack_idx].pop() {
+            stacks[instruction.destination_stack_idx].push(char_);
+        }
+    }
+}
+
+fn _part_2_execute_instruction(stacks: &mut [Vec<char>], instruction: Instruction) {
+    let source_stack_height = stacks[instruction.source_stack_idx].len();
+    let bottom_box_idx = source_stack_height - instruction.n_boxes;
+
+    let mut boxes_to_move: Vec<char> =
+        stacks[instruction.source_stack_idx].split_off(bottom_box_idx);
+    stacks[instruction.destination_stack_idx].append(&mut boxes_to_move);
+}
+
+pub fn part_1(mut stacks: Vec<Vec<char>>, instructions: Vec<Instruction>) -> String {
+    for instruction in instructions {
+        _part_1_execute_instruction(&mut stacks, instruction);
+    }
+
+    stacks
+        .iter()
+        .map(|stack| {
+            let height = stack.len();
+            stack[height - 1]
+        })
+        .collect::<String>()
+}
+
+pub fn part_2(mut stacks: Vec<Vec<char>>, instructions: Vec<Instruction>) -> String {
+    for instruction in instructions {
+        _part_2_execute_instruction(&mut stacks, instruction);
+    }
+
+    stacks
+        .iter()
+        .map(|stack| {
+            let height = stack.len();
+            stack[height - 1]
+        })
+        .collect::<String>()
+}
+
+#[derive(Default)]
+pub struct Day5;
+
+impl Params for Day5 {}
+
+impl Solution for Day5 {
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        let (stacks, instructions) = parse_input(input.lines().map(String::from));
+        let n_drawing_lines = input.lines().take_while(|line| !is_blank(line)).count();
+        check_moves(&stacks, &instructions, n_drawing_lines + 2)?;
+        Ok((stacks, instructions))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> String {
+        let (stacks, instructions) = input;
+        part_1(stacks.clone(), instructions.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> String {
+        let (stacks, instructions) = input;
+        part_2(stacks.clone(), instructions.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stack_line() {
+        assert_eq!(
+            parse_stack_line("[C]     [P]".to_string()),
+            vec![Some('C'), None, Some('P')]
+        );
+        assert_eq!(
+            parse_stack_line("    [D]".to_string()),
+            vec![None, Some('D')]
+        );
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(
+            parse_instruction("move 22 from 1 to 8".to_string()),
+            Instruction {
+                source_stack_idx: 0,
+                destination_stack_idx: 7,
+                n_boxes: 22,
+            }
+        )
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\n";
+        assert_eq!(validate(input), vec![]);
+
+        let diagnostics = validate("[N] [C]\n 1   2\n\nmove 1 from 2 on 1\n");
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::new(4, 15, "'to '", "move 1 from 2 on 1")]
+        );
+        assert_eq!(validate("[N] [C]\n 1   2\n").len(), 1);
+    }
+}
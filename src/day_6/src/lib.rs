@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use shared::error::ParseError;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+const PACKET_MARKER_LEN: usize = 4;
+const MESSAGE_MARKER_LEN: usize = 14;
+
+fn get_message_start_idx(message: &str, n_distinct: usize) -> Option<usize> {
+    let message_len: usize = message.len();
+    for char_idx in n_distinct..message_len {
+        if message[char_idx - n_distinct..char_idx]
+            .chars()
+            .collect::<HashSet<char>>()
+            .len()
+            == n_distinct
+        {
+            return Some(char_idx);
+        }
+    }
+    None
+}
+
+pub fn parse_input(mut reader: impl Iterator<Item = String>) -> String {
+    reader.next().unwrap()
+}
+
+/// A single line of lowercase letters, with a run of 14 different ones
+/// somewhere, which means there is a run of 4 as well
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = check_lines(input, |scanner| {
+        scanner.take_while("a lowercase letter", |c| c.is_ascii_lowercase())?;
+        scanner.end()
+    });
+    if let (true, Some(line)) = (diagnostics.is_empty(), input.lines().next()) {
+        if get_message_start_idx(line, MESSAGE_MARKER_LEN).is_none() {
+            let expected = format!("a run of {} different letters", MESSAGE_MARKER_LEN);
+            diagnostics.push(Diagnostic::new(1, 1, expected, line));
+        }
+    }
+    diagnostics
+}
+
+pub fn part_1(message: &str) -> usize {
+    get_message_start_idx(message, PACKET_MARKER_LEN).expect("no start-of-packet marker")
+}
+
+pub fn part_2(message: &str) -> usize {
+    get_message_start_idx(message, MESSAGE_MARKER_LEN).expect("no start-of-message marker")
+}
+
+#[derive(Default)]
+pub struct Day6;
+
+impl Params for Day6 {}
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_message_start_idx() {
+        assert_eq!(
+            get_message_start_idx("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4),
+            Some(11)
+        );
+        assert_eq!(
+            get_message_start_idx("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14),
+            Some(26)
+        );
+        assert_eq!(get_message_start_idx("abcabcabcabc", 4), None);
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, numbered_lines, Diagnostic};
+
+lazy_static! {
+    static ref CD_REGEX: Regex = Regex::new(r"^\$ cd ([a-zA-Z\.]*)$").unwrap();
+    static ref LS_REGEX: Regex = Regex::new(r"^\$ ls$").unwrap();
+    static ref FILE_LISTING_REGEX: Regex = Regex::new(r"^([0-9]*) ([a-zA-Z\.]*)$").unwrap();
+}
+
+const SYSTEM_DISK_SPACE: usize = 70_000_000;
+/// What the update needs free
+const REQUIRED_DISK_SPACE: usize = 30_000_000;
+
+#[derive(Clone, PartialEq, Eq)]
+enum FileType {
+    File,
+    Directory,
+}
+
+#[derive(Clone)]
+struct File {
+    id: usize,
+    file_type: FileType,
+    parent_file_id: Option<usize>,
+    children_file_names_by_id: HashMap<usize, String>,
+    children_file_ids_by_name: HashMap<String, usize>,
+    size: usize,
+}
+
+impl File {
+    fn new_directory(id: usize, parent_file_id: Option<usize>) -> File {
+        File {
+            id,
+            file_type: FileType::Directory,
+            parent_file_id,
+            children_file_names_by_id: HashMap::new(),
+            children_file_ids_by_name: HashMap::new(),
+            size: 0,
+        }
+    }
+
+    fn new_file(id: usize, parent_file_id: usize, size: usize) -> File {
+        File {
+            id,
+            file_type: FileType::File,
+            parent_file_id: Some(parent_file_id),
+            children_file_names_by_id: HashMap::new(),
+            children_file_ids_by_name: HashMap::new(),
+            size,
+        }
+    }
+
+    fn total_size(&self, file_system: &FileSystem) -> usize {
+        match self.file_type {
+            FileType::File => self.size,
+            FileType::Directory => self
+                .children_file_names_by_id
+                .keys()
+                .map(|file_id| file_system.get_file_by_id(*file_id).total_size(file_system))
+                .sum(),
+        }
+    }
+}
+
+pub struct FileSystem {
+    files: Vec<File>,
+}
+
+impl FileSystem {
+    fn new() -> FileSystem {
+        FileSystem {
+            files: vec![File::new_directory(0, None)],
+        }
+    }
+
+    fn make_directory(&mut self, name: String, parent_id: usize) -> usize {
+        let n_files = self.files.len();
+        let new_file = File::new_directory(n_files, Some(parent_id));
+        self.files.push(new_file);
+        self.files[parent_id]
+            .children_file_ids_by_name
+            .insert(name.clone(), n_files);
+        self.files[parent_id]
+            .children_file_names_by_id
+            .insert(n_files, name);
+        n_files
+    }
+
+    fn add_file(&mut self, name: String, parent_id: usize, size: usize) -> usize {
+        let n_files = self.files.len();
+        let new_file = File::new_file(n_files, parent_id, size);
+        let new_file_id = new_file.id;
+        self.files.push(new_file);
+        self.files[parent_id]
+            .children_file_ids_by_name
+            .insert(name.clone(), new_file_id);
+        self.files[parent_id]
+            .children_file_names_by_id
+            .insert(new_file_id, name);
+        n_files
+    }
+
+    fn get_file_by_id(&self, id: usize) -> &File {
+        &self.files[id]
+    }
+
+    fn directories(&self) -> Vec<File> {
+        self.files
+            .iter()
+            .filter(|file| file.file_type == FileType::Directory)
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn parse_input(mut reader: impl Iterator<Item = String>) -> FileSystem {
+    reader.next().unwrap(); // `$ cd /`
+    let mut file_system = FileSystem::new();
+    let mut cwd_id: usize = 0;
+    let mut cwd: File;
+
+    for command in reader {
+        cwd = file_system.get_file_by_id(cwd_id).clone();
+        if let Some(dest_directory_match) = CD_REGEX.captures(&command) {
+            let dest_directory_name: String =
+                dest_directory_match.get(1).unwrap().as_str().to_string();
+            if dest_directory_name == ".." {
+                cwd_id = cwd.parent_file_id.unwrap();
+            } else {
+                if cwd
+                    .children_file_ids_by_name
+                    .contains_key(&dest_directory_name)
+                {
+                    cwd_id = *cwd
+                        .children_file_ids_by_name
+                        .get(&dest_directory_name)
+                        .unwrap();
+                } else {
+                    cwd_id = file_system.make_directory(dest_directory_name, cwd.id);
+                }
+            }
+        } else if let Some(_ls_match) = LS_REGEX.captures(&command) {
+            continue;
+        } else if let Some(file_listing) = FILE_LISTING_REGEX.captures(&command) {
+            let new_file_name: String = file_listing.get(2).unwrap().as_str().to_string();
+            let new_file_size: usize = file_listing
+                .get(1)
+                .unwrap()
+                .as_str()
+                .parse::<usize>()
+                .unwrap();
+            file_system.add_file(new_file_name, cwd.id, new_file_size);
+        }
+    }
+
+    file_system
+}
+
+/// Commands starting with `$` and the listings they print
+/// The session starts at the root, and `cd` only goes into directories that
+/// an `ls` has listed
+fn check_directories(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut known: HashSet<Vec<&str>> = HashSet::from([Vec::new()]);
+    let mut cwd: Vec<&str> = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        if line_number == 1 && line != "$ cd /" {
+            diagnostics.push(Diagnostic::new(1, 1, "`$ cd /`", line));
+        }
+        if let Some(name) = line.strip_prefix("$ cd ") {
+            match name {
+                "/" => cwd.clear(),
+                ".." => {
+                    if cwd.pop().is_none() {
+                        diagnostics.push(Diagnostic::new(
+                            line_number,
+                            6,
+                            "a directory below the root",
+                            line,
+                        ));
+                    }
+                }
+                _ => {
+                    cwd.push(name);
+                    if !known.contains(&cwd) {
+                        diagnostics.push(Diagnostic::new(
+                            line_number,
+                            6,
+                            "a directory listed by ls",
+                            line,
+                        ));
+                        break;
+                    }
+                }
+            }
+        } else if let Some(name) = line.strip_prefix("dir ") {
+            let mut path = cwd.clone();
+            path.push(name);
+            known.insert(path);
+        }
+    }
+    diagnostics
+}
+
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let is_name_char = |c: char| c.is_ascii_alphabetic() || c == '.' || c == '/';
+    let mut diagnostics = check_lines(input, |scanner| {
+        if scanner.optional("$ ") {
+            if !scanner.optional("ls") {
+                scanner.literal("cd ")?;
+                scanner.take_while("a directory name", is_name_char)?;
+            }
+        } else if scanner.optional("dir ") {
+            scanner.take_while("a directory name", is_name_char)?;
+        } else {
+            scanner.unsigned()?;
+            scanner.literal(" ")?;
+            scanner.take_while("a file name", is_name_char)?;
+        }
+        scanner.end()
+    });
+    if diagnostics.is_empty() {
+        diagnostics = check_directories(input);
+    }
+    if diagnostics.is_empty() {
+        diagnostics = check_disk_space(input);
+    }
+    diagnostics
+}
+
+/// The files listed fit on the disk
+fn check_disk_space(input: &str) -> Vec<Diagnostic> {
+    let mut total_size: usize = 0;
+    for (line_number, line) in numbered_lines(input) {
+        let Some((size, _)) = line.split_once(' ') else {
+            continue;
+        };
+        let Ok(size) = size.parse::<usize>() else {
+            continue;
+        };
+        total_size = total_size.saturating_add(size);
+        if total_size > SYSTEM_DISK_SPACE {
+            let expected = format!("files totalling at most {}", SYSTEM_DISK_SPACE);
+            return vec![Diagnostic::new(line_number, 1, expected, line)];
+        }
+    }
+    Vec::new()
+}
+
+pub fn part_1(file_system: &FileSystem) -> usize {
+    let part_1_threshold: usize = 100_000;
+
+    file_system
+        .directories()
+        .iter()
+        .map(|directory| directory.total_size(file_system))
+        .filter(|total_size| total_size <= &part_1_threshold)
+        .sum()
+}
+
+pub fn part_2(file_system: &FileSystem) -> usize {
+    let remaining_disk_space =
+        SYSTEM_DISK_SPACE - file_system.get_file_by_id(0).total_size(file_system);
+    let disk_space_must_free = REQUIRED_DISK_SPACE.saturating_sub(remaining_disk_space);
+
+    // the root always frees enough, as the files fit on the disk
+    file_system
+        .directories()
+        .iter()
+        .map(|directory| directory.total_size(file_system))
+        .filter(|total_size| total_size >= &disk_space_must_free)
+        .min()
+        .unwrap()
+}
+
+#[derive(Default)]
+pub struct Day7;
+
+impl Params for Day7 {}
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt")
+            .part_1(95437)
+            .part_2(24933642)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::example_tests!(Day7);
+
+    #[test]
+    fn test_part_2_with_space_to_spare() {
+        let file_system = Day7.parse("$ cd /\n").unwrap();
+        assert_eq!(part_2(&file_system), 0);
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = "$ cd /\n$ ls\n40000000 a\n30000001 b\n";
+        assert_eq!(validate(input)[0].line, 4);
+        assert_eq!(validate(&input.replace("30000001", "30000000")), vec![]);
+    }
+}
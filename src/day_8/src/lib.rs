@@ -0,0 +1,159 @@
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::geom::Point2;
+use shared::grid::Grid;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{check_grid, check_not_empty, ensure_valid, numbered_lines, Diagnostic};
+
+pub struct Forest {
+    trees: Grid<u8>,
+}
+
+impl Forest {
+    fn from_reader(reader: impl Iterator<Item = String>) -> Result<Forest, ParseError> {
+        let trees = Grid::parse(reader, |c| c.to_digit(10).map(|height| height as u8))?;
+        Ok(Forest { trees })
+    }
+
+    fn is_on_edge(&self, tree: Point2<usize>) -> bool {
+        tree.y == 0
+            || tree.y == self.trees.n_rows() - 1
+            || tree.x == 0
+            || tree.x == self.trees.n_cols() - 1
+    }
+
+    /// Heights of the trees in each of the four directions, from the tree outwards
+    fn lines_of_sight(&self, tree: Point2<usize>) -> [Vec<u8>; 4] {
+        let row = self.trees.row(tree.y);
+        let col: Vec<u8> = self.trees.column(tree.x).copied().collect();
+        [
+            row[..tree.x].iter().rev().copied().collect(),
+            row[tree.x + 1..].to_vec(),
+            col[..tree.y].iter().rev().copied().collect(),
+            col[tree.y + 1..].to_vec(),
+        ]
+    }
+
+    fn is_tree_visible(&self, tree: Point2<usize>) -> bool {
+        if self.is_on_edge(tree) {
+            // tree is on the outside!
+            return true;
+        }
+
+        let tree_height: u8 = self.trees[tree];
+        // we can safely unwrap because we've already ruled out the edge trees
+        self.lines_of_sight(tree)
+            .iter()
+            .any(|heights| tree_height > *heights.iter().max().unwrap())
+    }
+
+    fn n_visible_trees(&self) -> usize {
+        self.trees
+            .points()
+            .filter(|tree| self.is_tree_visible(*tree))
+            .count()
+    }
+
+    /// Number of trees visible along a line of sight, up to and including
+    /// the first one that is at least as tall
+    fn _ntrees_visible(tree_height: u8, heights: &[u8]) -> usize {
+        match heights.iter().position(|height| *height >= tree_height) {
+            Some(idx) => idx + 1,
+            None => heights.len(),
+        }
+    }
+
+    fn tree_score(&self, tree: Point2<usize>) -> usize {
+        let tree_height: u8 = self.trees[tree];
+        self.lines_of_sight(tree)
+            .iter()
+            .map(|heights| Forest::_ntrees_visible(tree_height, heights))
+            .product()
+    }
+
+    fn max_trees_visible(&self) -> usize {
+        self.trees
+            .points()
+            .map(|tree| self.tree_score(tree))
+            .max()
+            .unwrap()
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Forest {
+    Forest::from_reader(reader).unwrap()
+}
+
+/// A rectangle of tree heights
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let mut diagnostics = check_not_empty(input);
+    diagnostics.extend(check_grid(&lines, "0123456789"));
+    diagnostics
+}
+
+pub fn part_1(forest: &Forest) -> usize {
+    forest.n_visible_trees()
+}
+
+pub fn part_2(forest: &Forest) -> usize {
+    forest.max_trees_visible()
+}
+
+#[derive(Default)]
+pub struct Day8;
+
+impl Params for Day8 {}
+
+impl Solution for Day8 {
+    type Input = Forest;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Forest::from_reader(input.lines().map(String::from))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("inputs/example.txt").part_1(21).part_2(8)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day8);
+
+    #[test]
+    fn test_tree_score_utilities() {
+        let forest = Forest::from_reader(
+            AocBufReader::from_path("inputs/example.txt")
+                .unwrap()
+                .map(Result::unwrap),
+        )
+        .unwrap();
+        let tree = Point2::new(2, 1);
+        let [west, east, north, south] = forest.lines_of_sight(tree);
+        assert_eq!(Forest::_ntrees_visible(forest.trees[tree], &north), 1);
+        assert_eq!(Forest::_ntrees_visible(forest.trees[tree], &south), 2);
+        assert_eq!(Forest::_ntrees_visible(forest.trees[tree], &west), 1);
+        assert_eq!(Forest::_ntrees_visible(forest.trees[tree], &east), 2);
+        assert_eq!(forest.tree_score(tree), 4);
+    }
+}
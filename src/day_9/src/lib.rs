@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+use std::iter;
+
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::geom::{Direction, Point2};
+use shared::params::Params;
+use shared::sim::{Driver, Simulation};
+use shared::solution::Solution;
+use shared::validate::{check_lines, ensure_valid, Diagnostic};
+
+type Coord = Point2<isize>;
+
+pub struct Instruction {
+    direction: Direction,
+    n_moves: usize,
+}
+
+#[derive(Clone)]
+struct Rope {
+    knots: Vec<Coord>,
+    /// Every move of the head, one position at a time
+    moves: Vec<Direction>,
+    n_moves_made: usize,
+    /// Where the tail has been after each move
+    visited: HashSet<Coord>,
+}
+
+impl Rope {
+    fn new(len: usize, instructions: &[Instruction]) -> Rope {
+        let mut knots: Vec<Coord> = Vec::new();
+        for _ in 0..len {
+            knots.push(Coord::new(0, 0));
+        }
+        let moves: Vec<Direction> = instructions
+            .iter()
+            .flat_map(|instruction| iter::repeat_n(instruction.direction, instruction.n_moves))
+            .collect();
+        Rope {
+            knots,
+            moves,
+            n_moves_made: 0,
+            visited: HashSet::new(),
+        }
+    }
+
+    fn _move(&mut self, direction: Direction) {
+        self.knots[0] += direction.to_vector();
+
+        for knot_idx in 1..self.length() {
+            let prev_knot_displacement = self.knots[knot_idx - 1] - self.knots[knot_idx];
+            if prev_knot_displacement.chebyshev_length() > 1 {
+                self.knots[knot_idx] += prev_knot_displacement.signum();
+            }
+        }
+    }
+
+    fn length(&self) -> usize {
+        self.knots.len()
+    }
+
+    fn tail_position(&self) -> Coord {
+        self.knots[self.length() - 1]
+    }
+}
+
+/// Each step moves the head by one position
+impl Simulation for Rope {
+    type Snapshot = Rope;
+
+    fn step(&mut self) -> bool {
+        self._move(self.moves[self.n_moves_made]);
+        self.n_moves_made += 1;
+        self.visited.insert(self.tail_position());
+        true
+    }
+
+    fn snapshot(&self) -> Rope {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Rope) {
+        *self = snapshot.clone();
+    }
+
+    fn is_done(&self) -> bool {
+        self.n_moves_made == self.moves.len()
+    }
+}
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<Instruction> {
+    let mut instructions: Vec<Instruction> = Vec::new();
+    for row in reader {
+        let mut direction_n_moves = row.split(' ');
+        let direction = direction_n_moves
+            .next()
+            .unwrap()
+            .chars()
+            .next()
+            .and_then(Direction::from_char)
+            .expect("Unexpected direction!");
+
+        let n_moves: usize = direction_n_moves.next().unwrap().parse::<usize>().unwrap();
+        instructions.push(Instruction { direction, n_moves });
+    }
+
+    instructions
+}
+
+/// `D N` moves of the head
+pub fn validate(input: &str) -> Vec<Diagnostic> {
+    check_lines(input, |scanner| {
+        scanner.one_of("UDLR")?;
+        scanner.literal(" ")?;
+        scanner.unsigned()?;
+        scanner.end()
+    })
+}
+
+fn simulate_rope(instructions: &[Instruction], rope_length: usize) -> usize {
+    let mut driver = Driver::new(Rope::new(rope_length, instructions));
+    driver.run_until_done();
+    driver.sim().visited.len()
+}
+
+pub fn part_1(instructions: &[Instruction]) -> usize {
+    simulate_rope(instructions, 2)
+}
+
+pub fn part_2(instructions: &[Instruction]) -> usize {
+    simulate_rope(instructions, 10)
+}
+
+#[derive(Default)]
+pub struct Day9;
+
+impl Params for Day9 {}
+
+impl Solution for Day9 {
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        ensure_valid(validate(input))?;
+        Ok(parse_input(input.lines().map(String::from)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        validate(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("inputs/example_1.txt").part_1(13).part_2(1),
+            Example::new("inputs/example_2.txt").part_2(36),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::AocBufReader;
+
+    shared::example_tests!(Day9);
+
+    #[test]
+    fn test_rope() {
+        let reader = AocBufReader::from_path("inputs/example_1.txt")
+            .unwrap()
+            .map(Result::unwrap);
+        let instructions = parse_input(reader);
+
+        let mut driver = Driver::new(Rope::new(2, &instructions));
+        assert_eq!(driver.run_until_done(), 24);
+        assert_eq!(driver.sim().visited.len(), 13);
+
+        // after `R 4` and two moves of `U 4`
+        driver.seek(6);
+        assert_eq!(driver.sim().knots, vec![Coord::new(4, 2), Coord::new(4, 1)]);
+        assert_eq!(driver.sim().tail_position(), Coord::new(4, 1));
+    }
+}
@@ -0,0 +1,288 @@
+//! Playing a day's animation in the terminal. Keys are read from the
+//! terminal itself, put into raw mode with `stty`, so that the puzzle input
+//! can still be piped in on stdin.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use shared::animate::{render_frame, Glyph, Scene, Viewport};
+
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// Lines below the frame, for the status line
+const STATUS_ROWS: usize = 1;
+
+/// What a key press asks for
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Key {
+    Pause,
+    Step,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    pub fn from_byte(byte: u8) -> Option<Key> {
+        match byte {
+            b' ' | b'p' => Some(Key::Pause),
+            b'n' | b'.' => Some(Key::Step),
+            b'b' | b',' => Some(Key::Back),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' | b'_' => Some(Key::Slower),
+            // Ctrl-C arrives as a byte, since signals are off in raw mode
+            b'q' | 3 => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the animation is running, and how long each frame is shown
+#[derive(Debug, PartialEq, Eq)]
+pub struct Playback {
+    pub paused: bool,
+    pub delay: Duration,
+}
+
+impl Playback {
+    pub fn faster(&mut self) {
+        self.delay = (self.delay / 2).max(MIN_DELAY);
+    }
+
+    pub fn slower(&mut self) {
+        self.delay = (self.delay * 2).min(MAX_DELAY);
+    }
+}
+
+fn status_line(title: &str, n_steps: usize, playback: &Playback, has_keys: bool) -> String {
+    let state = if playback.paused { "paused" } else { "playing" };
+    let mut line = format!(
+        "{}  step {}  {} ms  {}",
+        title,
+        n_steps,
+        playback.delay.as_millis(),
+        state
+    );
+    if has_keys {
+        line.push_str("  [space] pause  [n] step  [b] back  [+/-] speed  [q] quit");
+    }
+    line
+}
+
+/// Run `stty` on the controlling terminal, returning what it printed
+fn stty(args: &[&str]) -> Option<String> {
+    let tty = File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(tty)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The terminal's size as `(rows, columns)`
+fn terminal_size() -> Option<(usize, usize)> {
+    let size = stty(&["size"])?;
+    let (n_rows, n_cols) = size.split_once(' ')?;
+    Some((n_rows.parse().ok()?, n_cols.parse().ok()?))
+}
+
+/// The controlling terminal with line buffering, echo and signals off, so
+/// that single key presses arrive as they are made. Restored when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> Option<RawMode> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(RawMode { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+/// The terminal's cursor, hidden while playing. Shown again when dropped,
+/// however playing ends.
+struct HiddenCursor;
+
+impl HiddenCursor {
+    fn hide() -> io::Result<HiddenCursor> {
+        let mut out = io::stdout();
+        write!(out, "\x1b[?25l")?;
+        out.flush()?;
+        Ok(HiddenCursor)
+    }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        let mut out = io::stdout();
+        let _ = writeln!(out, "\x1b[?25h");
+        let _ = out.flush();
+    }
+}
+
+/// Key presses, read from the terminal on a thread of their own
+fn read_keys() -> Option<Receiver<Key>> {
+    let mut tty = File::open("/dev/tty").ok()?;
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut byte = [0u8; 1];
+        while tty.read(&mut byte).is_ok_and(|n_read| n_read == 1) {
+            if let Some(key) = Key::from_byte(byte[0]) {
+                if sender.send(key).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    Some(receiver)
+}
+
+fn draw(
+    out: &mut impl Write,
+    scene: &dyn Scene,
+    viewport: &Viewport,
+    status: &str,
+) -> io::Result<()> {
+    let frame = render_frame(&scene.glyphs(), viewport, Glyph::new('.'));
+    // home the cursor and clear what is left of each line, rather than the
+    // whole screen, so that frames don't flicker
+    write!(out, "\x1b[H")?;
+    for line in frame.lines() {
+        writeln!(out, "{}\x1b[K", line)?;
+    }
+    write!(out, "{}\x1b[K\x1b[J", status)?;
+    out.flush()
+}
+
+/// Play `scene` from where it is until it is done or has taken `max_steps`
+/// steps, then wait for `q`. Without a terminal to read keys from, it plays
+/// through once and returns.
+pub fn play(
+    scene: &mut dyn Scene,
+    title: &str,
+    delay: Duration,
+    max_steps: Option<usize>,
+) -> io::Result<()> {
+    let raw_mode = RawMode::enable();
+    let keys = raw_mode.as_ref().and_then(|_| read_keys());
+    let (n_rows, n_cols) = terminal_size().unwrap_or((24, 80));
+    let mut viewport = Viewport::new(n_rows.saturating_sub(STATUS_ROWS).max(1), n_cols);
+    let mut playback = Playback {
+        paused: false,
+        delay,
+    };
+
+    let _cursor = HiddenCursor::hide()?;
+    let mut out = io::stdout().lock();
+    write!(out, "\x1b[2J")?;
+    loop {
+        if let Some(focus) = scene.focus() {
+            viewport.follow(focus);
+        }
+        let status = status_line(title, scene.n_steps(), &playback, keys.is_some());
+        draw(&mut out, scene, &viewport, &status)?;
+
+        let at_end = scene.is_done() || max_steps.is_some_and(|max| scene.n_steps() >= max);
+        let key = match &keys {
+            None if at_end => break,
+            None => {
+                thread::sleep(playback.delay);
+                None
+            }
+            Some(keys) if playback.paused || at_end => match keys.recv() {
+                Ok(key) => Some(key),
+                Err(_) => break,
+            },
+            Some(keys) => match keys.recv_timeout(playback.delay) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            },
+        };
+
+        match key {
+            None => {
+                scene.step();
+            }
+            Some(Key::Pause) => playback.paused = !playback.paused,
+            Some(Key::Step) => {
+                if !at_end {
+                    scene.step();
+                }
+                playback.paused = true;
+            }
+            Some(Key::Back) => {
+                scene.step_back();
+                playback.paused = true;
+            }
+            Some(Key::Faster) => playback.faster(),
+            Some(Key::Slower) => playback.slower(),
+            Some(Key::Quit) => break,
+        }
+    }
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_from_byte() {
+        assert_eq!(Key::from_byte(b' '), Some(Key::Pause));
+        assert_eq!(Key::from_byte(b'n'), Some(Key::Step));
+        assert_eq!(Key::from_byte(b'b'), Some(Key::Back));
+        assert_eq!(Key::from_byte(b'+'), Some(Key::Faster));
+        assert_eq!(Key::from_byte(b'-'), Some(Key::Slower));
+        assert_eq!(Key::from_byte(3), Some(Key::Quit));
+        assert_eq!(Key::from_byte(b'x'), None);
+    }
+
+    #[test]
+    fn test_playback() {
+        let mut playback = Playback {
+            paused: false,
+            delay: Duration::from_millis(100),
+        };
+        playback.faster();
+        assert_eq!(playback.delay, Duration::from_millis(50));
+        playback.slower();
+        playback.slower();
+        assert_eq!(playback.delay, Duration::from_millis(200));
+        (0..20).for_each(|_| playback.faster());
+        assert_eq!(playback.delay, MIN_DELAY);
+        (0..20).for_each(|_| playback.slower());
+        assert_eq!(playback.delay, MAX_DELAY);
+    }
+
+    #[test]
+    fn test_status_line() {
+        let playback = Playback {
+            paused: true,
+            delay: Duration::from_millis(50),
+        };
+        assert_eq!(
+            status_line("day 14 part 1", 12, &playback, false),
+            "day 14 part 1  step 12  50 ms  paused"
+        );
+        assert!(status_line("day 14 part 1", 12, &playback, true).ends_with("[q] quit"));
+    }
+}
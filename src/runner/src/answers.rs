@@ -0,0 +1,233 @@
+use shared::error::ParseError;
+use shared::examples::Example;
+
+/// The answers file checked in next to the runner
+pub const ANSWERS: &str = include_str!("../answers.txt");
+
+/// The known answer to one part of a day, for one input file.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Answer {
+    pub day: usize,
+    pub part: usize,
+    /// Relative to the day crate, e.g. `inputs/example.txt`
+    pub input: String,
+    /// Puzzle constants to solve with, as `(name, value)`
+    pub params: Vec<(String, String)>,
+    pub expected: String,
+}
+
+/// Answers can span lines, e.g. letters drawn on a screen, but are kept on one
+/// line with `\n` for each newline and `\\` for each backslash
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    unescaped
+}
+
+/// Parse the tab separated answers file. Blank lines and lines
+/// starting with `#` are skipped.
+pub fn parse_answers(contents: &str) -> Result<Vec<Answer>, ParseError> {
+    let mut answers: Vec<Answer> = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.splitn(4, '\t').collect();
+        let [day, part, input, expected]: [&str; 4] = fields
+            .try_into()
+            .map_err(|_| ParseError::at_line(idx + 1, "expected day, part, input and answer"))?;
+        let parse_number = |field: &str, name: &str| {
+            field.parse::<usize>().map_err(|_| {
+                ParseError::at_line(idx + 1, format!("{} is not a number: '{}'", name, field))
+            })
+        };
+        answers.push(Answer {
+            day: parse_number(day, "day")?,
+            part: parse_number(part, "part")?,
+            input: input.to_string(),
+            params: Vec::new(),
+            expected: unescape(expected),
+        });
+    }
+    Ok(answers)
+}
+
+/// The answers to the examples a day declares
+pub fn from_examples(day: usize, examples: &[Example]) -> Vec<Answer> {
+    examples
+        .iter()
+        .flat_map(|example| {
+            example.answers.iter().map(move |(part, expected)| Answer {
+                day,
+                part: *part,
+                input: example.path.to_string(),
+                params: example
+                    .params
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect(),
+                expected: expected.clone(),
+            })
+        })
+        .collect()
+}
+
+/// What a day's examples leave uncovered: parts without an example answer,
+/// and example files that no example declares
+pub fn coverage_gaps(examples: &[Example], example_files: &[String]) -> Vec<String> {
+    if examples.is_empty() {
+        return vec!["no examples".to_string()];
+    }
+    let mut gaps: Vec<String> = (1..=2)
+        .filter(|part| {
+            !examples
+                .iter()
+                .any(|example| example.answers.iter().any(|(answered, _)| answered == part))
+        })
+        .map(|part| format!("no example for part {}", part))
+        .collect();
+    gaps.extend(
+        example_files
+            .iter()
+            .filter(|file| !examples.iter().any(|example| example.path == *file))
+            .map(|file| format!("{} is not declared", file)),
+    );
+    gaps
+}
+
+/// A line by line comparison of two answers, with `-` marking expected
+/// lines and `+` marking actual ones
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut diff_lines: Vec<String> = Vec::new();
+    for idx in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(idx), actual_lines.get(idx)) {
+            (Some(expected_line), Some(actual_line)) if expected_line == actual_line => {
+                diff_lines.push(format!("  {}", expected_line))
+            }
+            (expected_line, actual_line) => {
+                if let Some(expected_line) = expected_line {
+                    diff_lines.push(format!("- {}", expected_line));
+                }
+                if let Some(actual_line) = actual_line {
+                    diff_lines.push(format!("+ {}", actual_line));
+                }
+            }
+        }
+    }
+    diff_lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# comment\n\n1\t2\tinputs/part_1.txt\t#.\\n.#\n").unwrap();
+        assert_eq!(
+            answers,
+            vec![Answer {
+                day: 1,
+                part: 2,
+                input: "inputs/part_1.txt".to_string(),
+                params: Vec::new(),
+                expected: "#.\n.#".to_string(),
+            }]
+        );
+
+        assert_eq!(
+            parse_answers("1\t2\tinputs/part_1.txt"),
+            Err(ParseError::at_line(
+                1,
+                "expected day, part, input and answer"
+            ))
+        );
+        assert!(parse_answers("one\t2\tinputs/part_1.txt\t3").is_err());
+    }
+
+    #[test]
+    fn test_checked_in_answers() {
+        let answers = parse_answers(ANSWERS).unwrap();
+        for day in 1..=25 {
+            for part in 1..=2 {
+                assert!(answers
+                    .iter()
+                    .any(|answer| answer.day == day && answer.part == part));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_examples() {
+        let examples = vec![
+            Example::new("inputs/example.txt")
+                .part_1(26)
+                .part_2(56000011),
+            Example::new("inputs/example.txt")
+                .param("row", 11)
+                .part_1(28),
+        ];
+        let answers = from_examples(15, &examples);
+        assert_eq!(answers.len(), 3);
+        assert_eq!(
+            (answers[1].part, answers[1].expected.as_str()),
+            (2, "56000011")
+        );
+        assert_eq!(
+            answers[2],
+            Answer {
+                day: 15,
+                part: 1,
+                input: "inputs/example.txt".to_string(),
+                params: vec![("row".to_string(), "11".to_string())],
+                expected: "28".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_coverage_gaps() {
+        let files = vec![
+            "inputs/example_1.txt".to_string(),
+            "inputs/example_2.txt".to_string(),
+        ];
+        assert_eq!(coverage_gaps(&[], &files), vec!["no examples"]);
+        let examples = vec![Example::new("inputs/example_1.txt").part_1(13)];
+        assert_eq!(
+            coverage_gaps(&examples, &files),
+            vec![
+                "no example for part 2",
+                "inputs/example_2.txt is not declared"
+            ]
+        );
+        let examples = vec![
+            Example::new("inputs/example_1.txt").part_1(13).part_2(1),
+            Example::new("inputs/example_2.txt").part_2(36),
+        ];
+        assert!(coverage_gaps(&examples, &files).is_empty());
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("12", "13"), "- 12\n+ 13");
+        assert_eq!(diff("ab\ncd", "ab\nce\nf"), "  ab\n- cd\n+ ce\n+ f");
+    }
+}
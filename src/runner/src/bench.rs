@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use shared::error::ParseError;
+use shared::solution::StageTimes;
+
+use crate::json::{self, Value};
+
+/// Slowdowns smaller than this are never reported as regressions, since
+/// the fastest stages run in microseconds and their timings are mostly noise.
+const MIN_REGRESSION: Duration = Duration::from_millis(1);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let n_samples = samples.len();
+        let median = if n_samples % 2 == 1 {
+            samples[n_samples / 2]
+        } else {
+            (samples[n_samples / 2 - 1] + samples[n_samples / 2]) / 2
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[n_samples - 1],
+        }
+    }
+}
+
+/// Timings of one stage of one day over all runs
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BenchResult {
+    pub day: usize,
+    /// `parse`, `part_1` or `part_2`
+    pub stage: String,
+    pub stats: Stats,
+}
+
+/// Turn the timings of each run of a day into one result per stage
+pub fn bench_results(day: usize, runs: &[StageTimes]) -> Vec<BenchResult> {
+    StageTimes::NAMES
+        .iter()
+        .enumerate()
+        .map(|(stage_idx, stage)| BenchResult {
+            day,
+            stage: stage.to_string(),
+            stats: Stats::from_samples(
+                runs.iter()
+                    .map(|times| times.durations()[stage_idx])
+                    .collect(),
+            ),
+        })
+        .collect()
+}
+
+/// The results as JSON, one result per line so that baselines diff nicely
+pub fn to_json(runs: usize, results: &[BenchResult]) -> String {
+    let lines: Vec<String> = results
+        .iter()
+        .map(|result| {
+            Value::object([
+                ("day", Value::from(result.day)),
+                ("stage", Value::from(result.stage.as_str())),
+                ("min_ns", Value::from(result.stats.min.as_nanos() as u64)),
+                (
+                    "median_ns",
+                    Value::from(result.stats.median.as_nanos() as u64),
+                ),
+                ("max_ns", Value::from(result.stats.max.as_nanos() as u64)),
+            ])
+            .to_string()
+        })
+        .collect();
+    format!(
+        "{{\"runs\":{},\"results\":[\n{}\n]}}\n",
+        runs,
+        lines.join(",\n")
+    )
+}
+
+fn result_from_json(value: &Value) -> Option<BenchResult> {
+    let nanos = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_f64)
+            .map(|ns| Duration::from_nanos(ns as u64))
+    };
+    Some(BenchResult {
+        day: value.get("day")?.as_f64()? as usize,
+        stage: value.get("stage")?.as_str()?.to_string(),
+        stats: Stats {
+            min: nanos("min_ns")?,
+            median: nanos("median_ns")?,
+            max: nanos("max_ns")?,
+        },
+    })
+}
+
+/// Read back a baseline written by `to_json`
+pub fn from_json(text: &str) -> Result<Vec<BenchResult>, ParseError> {
+    json::parse(text)?
+        .get("results")
+        .and_then(Value::as_array)
+        .ok_or(ParseError::new("expected an object with a results array"))?
+        .iter()
+        .map(|value| {
+            result_from_json(value).ok_or(ParseError::new(format!(
+                "expected day, stage, min_ns, median_ns and max_ns in {}",
+                value
+            )))
+        })
+        .collect()
+}
+
+fn find_previous<'a>(result: &BenchResult, baseline: &'a [BenchResult]) -> Option<&'a BenchResult> {
+    baseline
+        .iter()
+        .find(|previous| previous.day == result.day && previous.stage == result.stage)
+}
+
+/// The relative change in median time compared to the baseline's result for
+/// the same day and stage, e.g. `0.25` for 25% slower
+pub fn median_change(result: &BenchResult, baseline: &[BenchResult]) -> Option<f64> {
+    let previous = find_previous(result, baseline)?.stats.median.as_secs_f64();
+    if previous == 0.0 {
+        return None;
+    }
+    Some((result.stats.median.as_secs_f64() - previous) / previous)
+}
+
+/// Whether the median time is more than `threshold` (relative) slower than
+/// the baseline's, by at least `MIN_REGRESSION`
+pub fn is_regression(result: &BenchResult, baseline: &[BenchResult], threshold: f64) -> bool {
+    let Some(previous) = find_previous(result, baseline) else {
+        return false;
+    };
+    let slowdown = result.stats.median.saturating_sub(previous.stats.median);
+    slowdown > MIN_REGRESSION
+        && slowdown.as_secs_f64() > previous.stats.median.as_secs_f64() * threshold
+}
+
+/// A duration in a readable unit, with three decimals
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.3}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.3}ms", nanos / 1e6)
+    } else {
+        format!("{:.3}s", nanos / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    fn result(day: usize, stage: &str, median_ms: u64) -> BenchResult {
+        BenchResult {
+            day,
+            stage: stage.to_string(),
+            stats: Stats::from_samples(millis(&[median_ms])),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 10]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_bench_results() {
+        let runs: Vec<StageTimes> = [1, 2, 3]
+            .iter()
+            .map(|ms| StageTimes {
+                parse: Duration::from_millis(*ms),
+                part_1: Duration::from_millis(ms * 10),
+                part_2: Duration::from_millis(ms * 100),
+            })
+            .collect();
+        let results = bench_results(7, &runs);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].stage, "part_1");
+        assert_eq!(results[1].stats.median, Duration::from_millis(20));
+        assert_eq!(results[2].stats.max, Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let results = vec![result(1, "parse", 2), result(16, "part_2", 1500)];
+        let text = to_json(5, &results);
+        assert_eq!(from_json(&text), Ok(results));
+        assert!(from_json("{\"runs\": 5}").is_err());
+        assert!(from_json("{\"results\": [{\"day\": 1}]}").is_err());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = vec![result(16, "part_1", 100), result(1, "parse", 0)];
+        let change = median_change(&result(16, "part_1", 125), &baseline).unwrap();
+        assert!((change - 0.25).abs() < 1e-9);
+        assert_eq!(median_change(&result(1, "parse", 1), &baseline), None);
+        assert!(is_regression(&result(16, "part_1", 125), &baseline, 0.1));
+        assert!(!is_regression(&result(16, "part_1", 105), &baseline, 0.1));
+        assert!(!is_regression(&result(16, "part_2", 500), &baseline, 0.1));
+        // too small to tell apart from noise
+        assert!(!is_regression(&result(1, "parse", 1), &baseline, 0.1));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(750)), "750ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.500ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.000s");
+    }
+}
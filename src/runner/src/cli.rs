@@ -0,0 +1,698 @@
+use shared::log::Level;
+
+use crate::output::Format;
+
+pub const USAGE: &str = "usage: aoc [-v|-vv|-vvv|-q] [--log TARGET=LEVEL] COMMAND ...
+    aoc run [--day N] [--year Y] [--part P] [--input PATH|-] [--example] [--format text|json|csv]
+            [--jobs J] [--PARAM VALUE ...]
+    aoc params [--day N]
+    aoc check [--day N] [--input PATH|-] [--example]
+    aoc generate --day N [--seed S] [--size N] [--output PATH] [--example] [--PARAM VALUE ...]
+    aoc fetch [--day N] [--year Y]
+    aoc new-day --day N [--year Y]
+    aoc submit --day N --part P [--year Y] [--input PATH|-] [--answer A]
+    aoc examples [--day N]
+    aoc animate --day N [--part P] [--input PATH|-] [--example] [--delay MS] [--steps N]
+    aoc verify [--day N] [--jobs J]
+    aoc bench [--day N] [--runs R] [--save PATH] [--baseline PATH] [--threshold PERCENT]
+
+fetch and submit only speak plain http. To reach https://adventofcode.com, run an
+http proxy that forwards to it and set base_url in the config file, or AOC_BASE_URL,
+to the proxy's http:// URL.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Params(ParamsArgs),
+    Check(CheckArgs),
+    Generate(GenerateArgs),
+    Fetch(FetchArgs),
+    NewDay(NewDayArgs),
+    Submit(SubmitArgs),
+    Examples(ExamplesArgs),
+    Animate(AnimateArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+}
+
+impl Command {
+    /// How many threads the command may use, if it was given `--jobs`
+    pub fn jobs(&self) -> Option<usize> {
+        match self {
+            Command::Run(args) => args.jobs,
+            Command::Verify(args) => args.jobs,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub day: Option<usize>,
+    pub year: usize,
+    pub part: Option<usize>,
+    pub input: Option<String>,
+    /// Use the example's input and puzzle constants
+    pub example: bool,
+    /// Puzzle constants to override, as `(name, value)`
+    pub params: Vec<(String, String)>,
+    pub format: Format,
+    pub jobs: Option<usize>,
+}
+
+impl Default for RunArgs {
+    fn default() -> RunArgs {
+        RunArgs {
+            day: None,
+            year: YEAR,
+            part: None,
+            input: None,
+            example: false,
+            params: Vec::new(),
+            format: Format::default(),
+            jobs: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct ParamsArgs {
+    pub day: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct CheckArgs {
+    pub day: Option<usize>,
+    pub input: Option<String>,
+    /// Check the example's input instead of the puzzle input
+    pub example: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenerateArgs {
+    pub day: usize,
+    pub seed: u64,
+    /// How many sensors, jets, ... the input lists
+    pub size: usize,
+    /// Where to write the input, instead of stdout
+    pub output: Option<String>,
+    /// Generate with the example's puzzle constants
+    pub example: bool,
+    /// Puzzle constants to generate for, as `(name, value)`
+    pub params: Vec<(String, String)>,
+}
+
+/// The year the days in this repository are from
+pub const YEAR: usize = 2022;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FetchArgs {
+    pub day: Option<usize>,
+    pub year: usize,
+}
+
+impl Default for FetchArgs {
+    fn default() -> FetchArgs {
+        FetchArgs {
+            day: None,
+            year: YEAR,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewDayArgs {
+    pub day: usize,
+    pub year: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SubmitArgs {
+    pub day: usize,
+    pub part: usize,
+    pub year: usize,
+    pub input: Option<String>,
+    /// Submit this instead of solving the part
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct ExamplesArgs {
+    pub day: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AnimateArgs {
+    pub day: usize,
+    pub part: usize,
+    pub input: Option<String>,
+    /// Animate the example's input with its puzzle constants
+    pub example: bool,
+    /// Milliseconds between frames
+    pub delay: u64,
+    /// Stop after this many steps, for simulations that don't stop by themselves
+    pub steps: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct VerifyArgs {
+    pub day: Option<usize>,
+    pub jobs: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    pub day: Option<usize>,
+    pub runs: usize,
+    /// Where to write the results as a JSON baseline
+    pub save: Option<String>,
+    /// A previously saved baseline to compare against
+    pub baseline: Option<String>,
+    /// How much slower than the baseline, in percent, counts as a regression
+    pub threshold: usize,
+}
+
+impl Default for BenchArgs {
+    fn default() -> BenchArgs {
+        BenchArgs {
+            day: None,
+            runs: 10,
+            save: None,
+            baseline: None,
+            threshold: 10,
+        }
+    }
+}
+
+/// Logging flags, which may appear anywhere on the command line
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct LogArgs {
+    /// Positive for each `-v`, negative for `-q`
+    pub verbosity: i8,
+    /// Levels for single targets, e.g. `--log day_19=debug`
+    pub targets: Vec<(String, Level)>,
+}
+
+fn parse_log_target(flag: &str, value: Option<String>) -> Result<(String, Level), String> {
+    let value = value.ok_or(format!("{} expects a value", flag))?;
+    value
+        .split_once('=')
+        .and_then(|(target, level)| Some((target.to_string(), Level::from_name(level)?)))
+        .ok_or(format!(
+            "{} expects TARGET=LEVEL with a level from error to trace, got '{}'",
+            flag, value
+        ))
+}
+
+/// The commands' flags that take no value. Every other flag is followed by one.
+const SWITCHES: [&str; 2] = ["--example", "-e"];
+
+/// Take the logging flags out of the arguments, returning them and the
+/// remaining arguments. The value of another flag is left alone, even if it
+/// looks like a logging flag.
+pub fn parse_log_args(
+    mut args: impl Iterator<Item = String>,
+) -> Result<(LogArgs, Vec<String>), String> {
+    let mut log_args = LogArgs::default();
+    let mut remaining: Vec<String> = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => log_args.verbosity += 1,
+            "-vv" => log_args.verbosity += 2,
+            "-vvv" => log_args.verbosity += 3,
+            "-q" | "--quiet" => log_args.verbosity -= 1,
+            "--log" => log_args.targets.push(parse_log_target(&arg, args.next())?),
+            flag if flag.starts_with('-') && !SWITCHES.contains(&flag) => {
+                remaining.push(arg);
+                remaining.extend(args.next());
+            }
+            _ => remaining.push(arg),
+        }
+    }
+    Ok((log_args, remaining))
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<usize, String> {
+    let value = value.ok_or(format!("{} expects a value", flag))?;
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn parse_jobs(flag: &str, value: Option<String>) -> Result<usize, String> {
+    match parse_number(flag, value)? {
+        0 => Err(format!("{} must be at least 1", flag)),
+        jobs => Ok(jobs),
+    }
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => run_args.day = Some(parse_number(&arg, args.next())?),
+            "--year" | "-y" => run_args.year = parse_number(&arg, args.next())?,
+            "--part" | "-p" => run_args.part = Some(parse_number(&arg, args.next())?),
+            "--input" | "-i" => {
+                run_args.input = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--example" | "-e" => run_args.example = true,
+            "--jobs" | "-j" => run_args.jobs = Some(parse_jobs(&arg, args.next())?),
+            "--format" | "-f" => {
+                let name = args.next().ok_or(format!("{} expects a value", arg))?;
+                run_args.format = Format::from_name(&name)
+                    .ok_or(format!("{} expects text, json or csv, got '{}'", arg, name))?;
+            }
+            _ => match arg.strip_prefix("--") {
+                Some(name) if !name.is_empty() => {
+                    let value = args.next().ok_or(format!("{} expects a value", arg))?;
+                    run_args.params.push((name.to_string(), value));
+                }
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            },
+        }
+    }
+
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err("--input can only be used together with --day".to_string());
+    }
+    if !run_args.params.is_empty() && run_args.day.is_none() {
+        return Err("parameters can only be used together with --day".to_string());
+    }
+    Ok(run_args)
+}
+
+fn parse_params_args(mut args: impl Iterator<Item = String>) -> Result<ParamsArgs, String> {
+    let mut params_args = ParamsArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => params_args.day = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(params_args)
+}
+
+fn parse_check_args(mut args: impl Iterator<Item = String>) -> Result<CheckArgs, String> {
+    let mut check_args = CheckArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => check_args.day = Some(parse_number(&arg, args.next())?),
+            "--input" | "-i" => {
+                check_args.input = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--example" | "-e" => check_args.example = true,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    if check_args.input.is_some() && check_args.day.is_none() {
+        return Err("--input can only be used together with --day".to_string());
+    }
+    Ok(check_args)
+}
+
+fn parse_generate_args(mut args: impl Iterator<Item = String>) -> Result<GenerateArgs, String> {
+    let mut day: Option<usize> = None;
+    let mut generate_args = GenerateArgs {
+        day: 0,
+        seed: 0,
+        size: 1000,
+        output: None,
+        example: false,
+        params: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--seed" | "-s" => generate_args.seed = parse_number(&arg, args.next())? as u64,
+            "--size" | "-n" => generate_args.size = parse_number(&arg, args.next())?,
+            "--output" | "-o" => {
+                generate_args.output = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--example" | "-e" => generate_args.example = true,
+            _ => match arg.strip_prefix("--") {
+                Some(name) if !name.is_empty() => {
+                    let value = args.next().ok_or(format!("{} expects a value", arg))?;
+                    generate_args.params.push((name.to_string(), value));
+                }
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            },
+        }
+    }
+
+    generate_args.day = day.ok_or("generate needs --day".to_string())?;
+    Ok(generate_args)
+}
+
+fn parse_fetch_args(mut args: impl Iterator<Item = String>) -> Result<FetchArgs, String> {
+    let mut fetch_args = FetchArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => fetch_args.day = Some(parse_number(&arg, args.next())?),
+            "--year" | "-y" => fetch_args.year = parse_number(&arg, args.next())?,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(fetch_args)
+}
+
+fn parse_new_day_args(mut args: impl Iterator<Item = String>) -> Result<NewDayArgs, String> {
+    let mut day: Option<usize> = None;
+    let mut year = YEAR;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--year" | "-y" => year = parse_number(&arg, args.next())?,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(NewDayArgs {
+        day: day.ok_or("new-day needs --day".to_string())?,
+        year,
+    })
+}
+
+fn parse_submit_args(mut args: impl Iterator<Item = String>) -> Result<SubmitArgs, String> {
+    let mut day: Option<usize> = None;
+    let mut part: Option<usize> = None;
+    let mut year = YEAR;
+    let mut input: Option<String> = None;
+    let mut answer: Option<String> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--part" | "-p" => part = Some(parse_number(&arg, args.next())?),
+            "--year" | "-y" => year = parse_number(&arg, args.next())?,
+            "--input" | "-i" => {
+                input = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--answer" | "-a" => {
+                answer = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(SubmitArgs {
+        day: day.ok_or("submit needs --day".to_string())?,
+        part: part.ok_or("submit needs --part".to_string())?,
+        year,
+        input,
+        answer,
+    })
+}
+
+fn parse_examples_args(mut args: impl Iterator<Item = String>) -> Result<ExamplesArgs, String> {
+    let mut examples_args = ExamplesArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => examples_args.day = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(examples_args)
+}
+
+fn parse_animate_args(mut args: impl Iterator<Item = String>) -> Result<AnimateArgs, String> {
+    let mut day: Option<usize> = None;
+    let mut animate_args = AnimateArgs {
+        day: 0,
+        part: 1,
+        input: None,
+        example: false,
+        delay: 100,
+        steps: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--part" | "-p" => animate_args.part = parse_number(&arg, args.next())?,
+            "--input" | "-i" => {
+                animate_args.input = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--example" | "-e" => animate_args.example = true,
+            "--delay" => animate_args.delay = parse_number(&arg, args.next())? as u64,
+            "--steps" | "-n" => animate_args.steps = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    animate_args.day = day.ok_or("animate needs --day".to_string())?;
+    Ok(animate_args)
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+    let mut verify_args = VerifyArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => verify_args.day = Some(parse_number(&arg, args.next())?),
+            "--jobs" | "-j" => verify_args.jobs = Some(parse_jobs(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(verify_args)
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => bench_args.day = Some(parse_number(&arg, args.next())?),
+            "--runs" | "-n" => bench_args.runs = parse_number(&arg, args.next())?,
+            "--save" => {
+                bench_args.save = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--baseline" => {
+                bench_args.baseline = Some(args.next().ok_or(format!("{} expects a value", arg))?)
+            }
+            "--threshold" => bench_args.threshold = parse_number(&arg, args.next())?,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    if bench_args.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+    Ok(bench_args)
+}
+
+/// Parse the command line arguments, excluding the program name.
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run_args(args)?)),
+        Some("params") => Ok(Command::Params(parse_params_args(args)?)),
+        Some("check") => Ok(Command::Check(parse_check_args(args)?)),
+        Some("generate") => Ok(Command::Generate(parse_generate_args(args)?)),
+        Some("fetch") => Ok(Command::Fetch(parse_fetch_args(args)?)),
+        Some("new-day") => Ok(Command::NewDay(parse_new_day_args(args)?)),
+        Some("submit") => Ok(Command::Submit(parse_submit_args(args)?)),
+        Some("examples") => Ok(Command::Examples(parse_examples_args(args)?)),
+        Some("animate") => Ok(Command::Animate(parse_animate_args(args)?)),
+        Some("verify") => Ok(Command::Verify(parse_verify_args(args)?)),
+        Some("bench") => Ok(Command::Bench(parse_bench_args(args)?)),
+        Some(command) => Err(format!("unknown command '{}'", command)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(|arg| arg.to_string())
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(args("run --day 16 --part 2 --input foo.txt")),
+            Ok(Command::Run(RunArgs {
+                day: Some(16),
+                part: Some(2),
+                input: Some("foo.txt".to_string()),
+                ..RunArgs::default()
+            }))
+        );
+        assert_eq!(
+            parse_args(args("run -d 3 -y 2023 -i - --format json")),
+            Ok(Command::Run(RunArgs {
+                day: Some(3),
+                year: 2023,
+                part: None,
+                input: Some("-".to_string()),
+                format: Format::Json,
+                ..RunArgs::default()
+            }))
+        );
+        assert_eq!(
+            parse_args(args("run -d 15 --example -j 4 --row 10 --bound 20")),
+            Ok(Command::Run(RunArgs {
+                day: Some(15),
+                example: true,
+                jobs: Some(4),
+                params: vec![
+                    ("row".to_string(), "10".to_string()),
+                    ("bound".to_string(), "20".to_string()),
+                ],
+                ..RunArgs::default()
+            }))
+        );
+        assert_eq!(
+            parse_args(args("params")),
+            Ok(Command::Params(ParamsArgs::default()))
+        );
+        assert_eq!(
+            parse_args(args("run")),
+            Ok(Command::Run(RunArgs::default()))
+        );
+        assert_eq!(
+            parse_args(args("check -d 22 --example")),
+            Ok(Command::Check(CheckArgs {
+                day: Some(22),
+                input: None,
+                example: true,
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "generate -d 15 --seed 7 -n 2000 -o big.txt --bound 100"
+            )),
+            Ok(Command::Generate(GenerateArgs {
+                day: 15,
+                seed: 7,
+                size: 2000,
+                output: Some("big.txt".to_string()),
+                example: false,
+                params: vec![("bound".to_string(), "100".to_string())],
+            }))
+        );
+        assert_eq!(
+            parse_args(args("fetch -d 1 --year 2021")),
+            Ok(Command::Fetch(FetchArgs {
+                day: Some(1),
+                year: 2021,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("fetch")),
+            Ok(Command::Fetch(FetchArgs::default()))
+        );
+        assert_eq!(
+            parse_args(args("new-day -d 1 --year 2023")),
+            Ok(Command::NewDay(NewDayArgs { day: 1, year: 2023 }))
+        );
+        assert_eq!(
+            parse_args(args("submit -d 1 -p 2 --answer 45000")),
+            Ok(Command::Submit(SubmitArgs {
+                day: 1,
+                part: 2,
+                year: YEAR,
+                input: None,
+                answer: Some("45000".to_string()),
+            }))
+        );
+        assert_eq!(
+            parse_args(args("examples -d 9")),
+            Ok(Command::Examples(ExamplesArgs { day: Some(9) }))
+        );
+        assert_eq!(
+            parse_args(args("animate -d 17 -e --delay 20 --steps 100")),
+            Ok(Command::Animate(AnimateArgs {
+                day: 17,
+                part: 1,
+                input: None,
+                example: true,
+                delay: 20,
+                steps: Some(100),
+            }))
+        );
+        assert_eq!(
+            parse_args(args("verify -d 10 --jobs 2")),
+            Ok(Command::Verify(VerifyArgs {
+                day: Some(10),
+                jobs: Some(2),
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "bench -d 16 -n 3 --save new.json --baseline old.json --threshold 5"
+            )),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(16),
+                runs: 3,
+                save: Some("new.json".to_string()),
+                baseline: Some("old.json".to_string()),
+                threshold: 5,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("bench")),
+            Ok(Command::Bench(BenchArgs::default()))
+        );
+    }
+
+    #[test]
+    fn test_parse_log_args() {
+        let (log_args, remaining) =
+            parse_log_args(args("-v run -d 19 -vv --log day_19=trace")).unwrap();
+        assert_eq!(
+            log_args,
+            LogArgs {
+                verbosity: 3,
+                targets: vec![("day_19".to_string(), Level::Trace)],
+            }
+        );
+        assert_eq!(remaining, vec!["run", "-d", "19"]);
+
+        let (log_args, remaining) = parse_log_args(args("-q verify")).unwrap();
+        assert_eq!(log_args.verbosity, -1);
+        assert_eq!(remaining, vec!["verify"]);
+
+        // values of other flags are kept, whatever they look like
+        let (log_args, remaining) =
+            parse_log_args(args("submit -d 1 -p 1 --answer -q -v")).unwrap();
+        assert_eq!(log_args.verbosity, 1);
+        assert_eq!(
+            remaining,
+            vec!["submit", "-d", "1", "-p", "1", "--answer", "-q"]
+        );
+        let (log_args, remaining) = parse_log_args(args("run --example -v")).unwrap();
+        assert_eq!(log_args.verbosity, 1);
+        assert_eq!(remaining, vec!["run", "--example"]);
+
+        assert!(parse_log_args(args("run --log day_19")).is_err());
+        assert!(parse_log_args(args("run --log day_19=loud")).is_err());
+        assert!(parse_log_args(args("run --log")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(args("")).is_err());
+        assert!(parse_args(args("walk")).is_err());
+        assert!(parse_args(args("run --day")).is_err());
+        assert!(parse_args(args("run --day sixteen")).is_err());
+        assert!(parse_args(args("run --input foo.txt")).is_err());
+        assert!(parse_args(args("run --row 10")).is_err());
+        assert!(parse_args(args("check --input foo.txt")).is_err());
+        assert!(parse_args(args("check -d 3 --row 10")).is_err());
+        assert!(parse_args(args("generate --size 10")).is_err());
+        assert!(parse_args(args("generate -d 17 --seed")).is_err());
+        assert!(parse_args(args("fetch --year last")).is_err());
+        assert!(parse_args(args("new-day --year 2023")).is_err());
+        assert!(parse_args(args("submit -d 1")).is_err());
+        assert!(parse_args(args("submit -p 1")).is_err());
+        assert!(parse_args(args("run --format xml")).is_err());
+        assert!(parse_args(args("run --jobs 0")).is_err());
+        assert!(parse_args(args("verify -j many")).is_err());
+        assert!(parse_args(args("run -d 15 --row")).is_err());
+        assert!(parse_args(args("run -d 15 row 10")).is_err());
+        assert!(parse_args(args("verify --part 1")).is_err());
+        assert!(parse_args(args("examples --part 1")).is_err());
+        assert!(parse_args(args("bench --runs 0")).is_err());
+        assert!(parse_args(args("animate --part 2")).is_err());
+        assert!(parse_args(args("animate -d 14 --delay fast")).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+//! Requests to the puzzle site, with the session cookie and rate limiting
+//! that every request needs.
+
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use shared::info;
+
+use crate::config::Config;
+use crate::http::{self, Response};
+
+/// Spaces out requests by at least `interval`. The time of the last request
+/// is kept in a file, so that it also holds across separate runs.
+pub struct RateLimiter {
+    stamp_path: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(stamp_path: PathBuf, interval: Duration) -> RateLimiter {
+        RateLimiter {
+            stamp_path,
+            interval,
+        }
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis = fs::read_to_string(&self.stamp_path)
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    /// How long to wait before the next request may be sent
+    pub fn delay(&self) -> Duration {
+        match self.last_request() {
+            // a last request in the future means the clock moved, so wait in full
+            Some(last) => self.interval.saturating_sub(
+                SystemTime::now()
+                    .duration_since(last)
+                    .unwrap_or(Duration::ZERO),
+            ),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Wait until the next request may be sent, then note that it is being sent
+    pub fn wait(&self) -> Result<(), String> {
+        let delay = self.delay();
+        if !delay.is_zero() {
+            info!("waiting {:?} between requests", delay);
+            thread::sleep(delay);
+        }
+
+        if let Some(dir) = self.stamp_path.parent() {
+            fs::create_dir_all(dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        // rounded up, so that the wait is never short of the interval
+        let millis = now.as_nanos().div_ceil(1_000_000);
+        fs::write(&self.stamp_path, millis.to_string())
+            .map_err(|error| format!("{}: {}", self.stamp_path.display(), error))
+    }
+}
+
+pub struct Client {
+    config: Config,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Client {
+        let limiter = RateLimiter::new(
+            config.cache_dir.join(".last_request"),
+            config.request_interval,
+        );
+        Client { config, limiter }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn request(&self, method: &str, path: &str, body: Option<&str>) -> Result<Response, String> {
+        let cookie = format!("session={}", self.config.session()?);
+        let url = format!("{}{}", self.config.base_url, path);
+        self.limiter.wait()?;
+        info!("{} {}", method, url);
+        let mut headers = vec![
+            ("Cookie", cookie.as_str()),
+            ("User-Agent", self.config.user_agent.as_str()),
+        ];
+        if body.is_some() {
+            headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+        }
+        http::request(method, &url, &headers, body)
+    }
+
+    /// The puzzle input of a day, as served
+    pub fn input(&self, year: usize, day: usize) -> Result<String, String> {
+        let response = self.request("GET", &format!("/{}/day/{}/input", year, day), None)?;
+        if response.status != 200 {
+            return Err(format!(
+                "could not fetch the input of {} day {}: status {}: {}",
+                year,
+                day,
+                response.status,
+                response.body.lines().next().unwrap_or("")
+            ));
+        }
+        Ok(response.body)
+    }
+
+    /// Post an answer, returning the page the site responds with
+    pub fn answer(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<String, String> {
+        let form = format!("level={}&answer={}", part, form_encode(answer));
+        let response = self.request(
+            "POST",
+            &format!("/{}/day/{}/answer", year, day),
+            Some(&form),
+        )?;
+        if response.status != 200 {
+            return Err(format!(
+                "could not submit an answer to {} day {}: status {}",
+                year, day, response.status
+            ));
+        }
+        Ok(response.body)
+    }
+}
+
+/// Percent-encode everything but letters, digits and `-._~`
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    use crate::stub::{test_config, StubServer};
+
+    #[test]
+    fn test_rate_limiter() {
+        let config = test_config("http://127.0.0.1:1", "rate-limiter");
+        let limiter = RateLimiter::new(
+            config.cache_dir.join(".last_request"),
+            config.request_interval,
+        );
+        assert_eq!(limiter.delay(), Duration::ZERO);
+
+        let start = Instant::now();
+        limiter.wait().unwrap();
+        assert!(limiter.delay() > Duration::ZERO);
+        limiter.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        // a second limiter over the same file, as in a later run
+        let later = RateLimiter::new(
+            config.cache_dir.join(".last_request"),
+            config.request_interval,
+        );
+        assert!(later.delay() > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("-1234"), "-1234");
+        assert_eq!(form_encode("a b&c=d"), "a%20b%26c%3Dd");
+    }
+
+    #[test]
+    fn test_input() {
+        let server = StubServer::start(|request| match request.path.as_str() {
+            "/2022/day/1/input" => (200, "1000\n2000\n".to_string()),
+            _ => (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".to_string(),
+            ),
+        });
+        let client = Client::new(test_config(&server.base_url, "client-input"));
+        assert_eq!(client.input(2022, 1), Ok("1000\n2000\n".to_string()));
+        let error = client.input(2022, 26).unwrap_err();
+        assert!(error.contains("status 404: Please don't"));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("Cookie"), Some("session=53616c"));
+        assert_eq!(requests[0].header("User-Agent"), Some("aoc-runner tests"));
+
+        let mut config = test_config(&server.base_url, "client-no-session");
+        config.session = None;
+        assert!(Client::new(config).input(2022, 1).is_err());
+        assert_eq!(server.requests().len(), 2);
+    }
+}
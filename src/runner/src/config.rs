@@ -0,0 +1,161 @@
+//! Settings for talking to the puzzle site. They are read from a config file
+//! of `key = value` lines, `$AOC_CONFIG` or else `~/.config/aoc/config`, and
+//! the session token and base URL can be overridden by `$AOC_SESSION` and
+//! `$AOC_BASE_URL`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Config {
+    /// The value of the site's `session` cookie, from a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Downloaded inputs are kept under here, one directory per year
+    pub cache_dir: PathBuf,
+    /// Sent with every request, so the site's operators know who to contact
+    pub user_agent: String,
+    /// The least time to leave between two requests to the site
+    pub request_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            cache_dir: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join("..")
+                .join("inputs"),
+            user_agent: "aoc-runner (std only)".to_string(),
+            request_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Config {
+    /// The settings for talking to the site, failing up front if the site
+    /// can't be reached with them
+    pub fn load() -> Result<Config, String> {
+        let config = Config::read()?;
+        config.check_base_url()?;
+        Ok(config)
+    }
+
+    /// The defaults, updated by the config file if there is one, then the environment
+    pub fn read() -> Result<Config, String> {
+        let mut config = Config::default();
+        let file_path = env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .or_else(default_config_path);
+        if let Some(file_path) = file_path.filter(|file_path| file_path.exists()) {
+            let text = fs::read_to_string(&file_path)
+                .map_err(|error| format!("{}: {}", file_path.display(), error))?;
+            config
+                .apply_file(&text)
+                .map_err(|error| format!("{}: {}", file_path.display(), error))?;
+        }
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url.trim_end_matches('/').to_string();
+        }
+        Ok(config)
+    }
+
+    /// Apply the settings of a config file. Blank lines and lines starting
+    /// with `#` are skipped.
+    pub fn apply_file(&mut self, text: &str) -> Result<(), String> {
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected key = value", idx + 1))?;
+            self.set(key.trim(), value.trim())
+                .map_err(|error| format!("line {}: {}", idx + 1, error))?;
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "session" => self.session = Some(value.to_string()),
+            "base_url" => self.base_url = value.trim_end_matches('/').to_string(),
+            "cache_dir" => self.cache_dir = PathBuf::from(value),
+            "user_agent" => self.user_agent = value.to_string(),
+            "request_interval_ms" => {
+                let millis = value
+                    .parse::<u64>()
+                    .map_err(|_| format!("expected a number of milliseconds, got '{}'", value))?;
+                self.request_interval = Duration::from_millis(millis)
+            }
+            _ => return Err(format!("unknown setting '{}'", key)),
+        }
+        Ok(())
+    }
+
+    /// The client only speaks plain http, so the real site, which needs
+    /// https, has to be reached through a proxy
+    fn check_base_url(&self) -> Result<(), String> {
+        if self.base_url.starts_with("http://") {
+            return Ok(());
+        }
+        Err(format!(
+            "the base URL {} is not http://, and this client has no https: \
+             fetch and submit need an http proxy that forwards to https://adventofcode.com, \
+             with AOC_BASE_URL or base_url = URL in the config file set to the proxy's \
+             http:// URL",
+            self.base_url
+        ))
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or(
+            "no session token: set AOC_SESSION or add session = TOKEN to the config file"
+                .to_string(),
+        )
+    }
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| {
+        PathBuf::from(home)
+            .join(".config")
+            .join("aoc")
+            .join("config")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_file() {
+        let mut config = Config::default();
+        config
+            .apply_file(
+                "# from the browser's cookies\nsession = 53616c\n\nbase_url = http://127.0.0.1:8000/\nrequest_interval_ms = 250\n",
+            )
+            .unwrap();
+        assert_eq!(config.session(), Ok("53616c"));
+        assert_eq!(config.base_url, "http://127.0.0.1:8000");
+        assert_eq!(config.request_interval, Duration::from_millis(250));
+
+        assert!(config.apply_file("session").is_err());
+        assert!(config.apply_file("colour = blue").is_err());
+        assert!(config.apply_file("request_interval_ms = soon").is_err());
+        assert!(Config::default().session().is_err());
+
+        assert_eq!(config.check_base_url(), Ok(()));
+        let error = Config::default().check_base_url().unwrap_err();
+        assert!(error.contains("http proxy"));
+    }
+}
@@ -0,0 +1,96 @@
+//! Downloaded puzzle inputs, cached as `CACHE_DIR/YEAR/day_N.txt`, where the
+//! runner looks for a day's input when it has none checked in. A cached
+//! input is never downloaded again.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::client::Client;
+
+pub fn cache_path(cache_dir: &Path, year: usize, day: usize) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day_{}.txt", day))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already cached, nothing was sent
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
+
+/// The path to the input of a day, downloading it first if it is not cached
+pub fn fetch(client: &Client, year: usize, day: usize) -> Result<Fetched, String> {
+    let path = cache_path(&client.config().cache_dir, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+    let dir = path.parent().expect("cache paths have a year directory");
+    fs::create_dir_all(dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+    // write under another name first, so that an interrupted write is never
+    // mistaken for a cached input
+    let partial_path = path.with_extension("partial");
+    fs::write(&partial_path, input)
+        .and_then(|_| fs::rename(&partial_path, &path))
+        .map_err(|error| format!("{}: {}", path.display(), error))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::{test_config, StubServer};
+
+    #[test]
+    fn test_fetch() {
+        let server = StubServer::start(|request| match request.path.as_str() {
+            "/2022/day/6/input" => (200, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".to_string()),
+            "/2022/day/7/input" => (200, "$ ls\n".to_string()),
+            _ => (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+                    .to_string(),
+            ),
+        });
+        let client = Client::new(test_config(&server.base_url, "fetch"));
+        let cache_dir = client.config().cache_dir.clone();
+
+        let fetched = fetch(&client, 2022, 6).unwrap();
+        assert_eq!(
+            fetched,
+            Fetched::Downloaded(cache_dir.join("2022").join("day_6.txt"))
+        );
+        assert_eq!(
+            fs::read_to_string(fetched.path()).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"
+        );
+
+        // never fetched twice
+        assert_eq!(
+            fetch(&client, 2022, 6),
+            Ok(Fetched::Cached(cache_dir.join("2022").join("day_6.txt")))
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        assert!(matches!(
+            fetch(&client, 2022, 7),
+            Ok(Fetched::Downloaded(_))
+        ));
+        assert!(fetch(&client, 2021, 7)
+            .unwrap_err()
+            .contains("Please log in"));
+        assert!(!cache_path(&cache_dir, 2021, 7).exists());
+        assert_eq!(server.requests().len(), 3);
+    }
+}
@@ -0,0 +1,233 @@
+//! Just enough HTTP/1.1 over `std::net` to talk to the puzzle site. There is
+//! no TLS, so only `http://` URLs work, e.g. a local stub server or a proxy
+//! that forwards to the real site over https.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Url {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+fn parse_url(url: &str) -> Result<Url, String> {
+    let rest = url.strip_prefix("http://").ok_or_else(|| {
+        if url.starts_with("https://") {
+            format!(
+                "cannot fetch {}: https is not supported, use an http proxy as the base URL",
+                url
+            )
+        } else {
+            format!("expected an http:// URL, got '{}'", url)
+        }
+    })?;
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], &rest[idx..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse::<u16>()
+                .map_err(|_| format!("invalid port in '{}'", url))?,
+        ),
+        None => (authority, 80),
+    };
+    if host.is_empty() {
+        return Err(format!("missing host in '{}'", url));
+    }
+    Ok(Url {
+        host: host.to_string(),
+        port,
+        path: path.to_string(),
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Undo `Transfer-Encoding: chunked`
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, String> {
+    let mut decoded: Vec<u8> = Vec::new();
+    loop {
+        let line_end = find(body, b"\r\n").ok_or("truncated chunk size")?;
+        let size_text = String::from_utf8_lossy(&body[..line_end]);
+        // chunk extensions after a `;` are allowed and ignored
+        let size_text = size_text.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size_text, 16)
+            .map_err(|_| format!("invalid chunk size '{}'", size_text))?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Ok(decoded);
+        }
+        if body.len() < size {
+            return Err("truncated chunk".to_string());
+        }
+        decoded.extend_from_slice(&body[..size]);
+        body = body[size..].strip_prefix(b"\r\n").unwrap_or(&body[size..]);
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, String> {
+    let head_end = find(raw, b"\r\n\r\n").ok_or("truncated response headers")?;
+    let head = String::from_utf8_lossy(&raw[..head_end]);
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or("");
+    let status = status_line
+        .split(' ')
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or(format!("invalid status line '{}'", status_line))?;
+
+    let mut chunked = false;
+    let mut content_length: Option<usize> = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse::<usize>().ok();
+        }
+    }
+
+    let body = &raw[head_end + 4..];
+    let body = if chunked {
+        decode_chunked(body)?
+    } else {
+        match content_length {
+            Some(length) if length <= body.len() => body[..length].to_vec(),
+            Some(_) => return Err("truncated response body".to_string()),
+            None => body.to_vec(),
+        }
+    };
+    Ok(Response {
+        status,
+        body: String::from_utf8(body).map_err(|_| "response body is not UTF-8")?,
+    })
+}
+
+/// Send a request and wait for the whole response. Every request uses its
+/// own connection.
+pub fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let url = parse_url(url)?;
+    let address = format!("{}:{}", url.host, url.port);
+    let mut stream =
+        TcpStream::connect(&address).map_err(|error| format!("{}: {}", address, error))?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        .map_err(|error| error.to_string())?;
+
+    let host = if url.port == 80 {
+        url.host.clone()
+    } else {
+        address.clone()
+    };
+    let mut message = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        method, url.path, host
+    );
+    for (name, value) in headers {
+        message.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if let Some(body) = body {
+        message.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    message.push_str("\r\n");
+    message.push_str(body.unwrap_or(""));
+
+    let mut raw: Vec<u8> = Vec::new();
+    stream
+        .write_all(message.as_bytes())
+        .and_then(|_| stream.read_to_end(&mut raw))
+        .map_err(|error| format!("{}: {}", address, error))?;
+    parse_response(&raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            parse_url("http://127.0.0.1:8080/2022/day/1/input"),
+            Ok(Url {
+                host: "127.0.0.1".to_string(),
+                port: 8080,
+                path: "/2022/day/1/input".to_string(),
+            })
+        );
+        assert_eq!(parse_url("http://localhost").unwrap().path, "/");
+        assert_eq!(parse_url("http://localhost/").unwrap().port, 80);
+        assert!(parse_url("https://adventofcode.com")
+            .unwrap_err()
+            .contains("https is not supported"));
+        assert!(parse_url("localhost:80/").is_err());
+        assert!(parse_url("http://localhost:http/").is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n1\n2\n3";
+        assert_eq!(
+            parse_response(raw),
+            Ok(Response {
+                status: 200,
+                body: "1\n2\n3".to_string(),
+            })
+        );
+
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1\n2\n\r\n2;x=y\r\n3\n\r\n0\r\n\r\n";
+        assert_eq!(parse_response(raw).unwrap().body, "1\n2\n3\n");
+
+        let raw = b"HTTP/1.1 404 Not Found\r\n\r\nnope";
+        assert_eq!(parse_response(raw).unwrap().status, 404);
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\nshort").is_err());
+        assert!(parse_response(b"garbage").is_err());
+    }
+
+    #[test]
+    fn test_request() {
+        let server = StubServer::start(|request| (200, format!("got {}", request.path)));
+        let response = request(
+            "GET",
+            &format!("{}/some/path", server.base_url),
+            &[("Cookie", "session=abc")],
+            None,
+        )
+        .unwrap();
+        assert_eq!(response.body, "got /some/path");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+
+        request("POST", &server.base_url, &[], Some("level=1&answer=42")).unwrap();
+        assert_eq!(server.requests()[1].body, "level=1&answer=42");
+    }
+}
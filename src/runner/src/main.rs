@@ -0,0 +1,628 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use shared::error::AocError;
+use shared::examples::Example;
+use shared::generate::Rng;
+use shared::input::AocBufReader;
+use shared::log::{self, Level};
+use shared::pool::{self, Pool};
+use shared::solution::DynSolution;
+
+mod animate;
+mod answers;
+mod bench;
+mod cli;
+mod client;
+mod config;
+mod fetch;
+mod http;
+mod json;
+mod output;
+mod registry;
+mod scaffold;
+#[cfg(test)]
+mod stub;
+mod submit;
+
+use answers::Answer;
+use bench::BenchResult;
+use cli::{
+    AnimateArgs, BenchArgs, CheckArgs, Command, ExamplesArgs, FetchArgs, GenerateArgs, NewDayArgs,
+    ParamsArgs, RunArgs, SubmitArgs, VerifyArgs,
+};
+use client::Client;
+use config::Config;
+use fetch::Fetched;
+use output::{Format, Record};
+use registry::Day;
+use submit::Verdict;
+
+/// Each day keeps its puzzle input next to its crate, e.g. `src/day_16/inputs/part_1.txt`,
+/// or else the input downloaded by `aoc fetch` is used
+fn default_input_path(day: &Day) -> PathBuf {
+    let checked_in = day.dir().join("inputs").join("part_1.txt");
+    if checked_in.exists() {
+        return checked_in;
+    }
+    Config::read()
+        .map(|config| fetch::cache_path(&config.cache_dir, day.year, day.day))
+        .ok()
+        .filter(|fetched| fetched.exists())
+        .unwrap_or(checked_in)
+}
+
+/// The example `--example` runs: the first one the day declares with an
+/// answer for `part`, or else its first one
+fn declared_example(day: &Day, part: Option<usize>) -> Option<Example> {
+    let examples = day.solution(true).examples();
+    let has_part = |example: &&Example| {
+        part.is_none_or(|part| {
+            example
+                .answers
+                .iter()
+                .any(|(answer_part, _)| *answer_part == part)
+        })
+    };
+    examples.iter().find(has_part).or(examples.first()).cloned()
+}
+
+/// The example from the puzzle text, as the day declares it, e.g.
+/// `src/day_9/inputs/example_1.txt`
+fn example_input_path(day: &Day, part: Option<usize>) -> PathBuf {
+    let path = declared_example(day, part).map_or("inputs/example.txt", |example| example.path);
+    day.dir().join(path)
+}
+
+/// The day with its puzzle constants, or with the example's, including any
+/// the example declares on top
+fn day_solution(
+    day: &Day,
+    example: bool,
+    part: Option<usize>,
+) -> Result<Box<dyn DynSolution>, String> {
+    let mut solution = day.solution(example);
+    let example_params = match example {
+        true => declared_example(day, part).map_or(Vec::new(), |example| example.params),
+        false => Vec::new(),
+    };
+    for (name, value) in example_params {
+        solution
+            .set_param(name, &value)
+            .map_err(|error| format!("day {}: {}", day.day, error))?;
+    }
+    Ok(solution)
+}
+
+fn lookup_day(year: usize, day: usize) -> Result<&'static Day, String> {
+    registry::get_day(year, day).ok_or_else(|| {
+        if year == cli::YEAR {
+            format!("day {} is not registered", day)
+        } else {
+            format!("{} day {} is not registered", year, day)
+        }
+    })
+}
+
+/// `-` reads the input from stdin, so it can be piped in. `part` picks the
+/// example, for days with several.
+fn read_input(
+    input: Option<&str>,
+    day: &Day,
+    example: bool,
+    part: Option<usize>,
+) -> Result<String, AocError> {
+    match input {
+        Some("-") => AocBufReader::from_stdin().read_to_string(),
+        Some(file_path) => AocBufReader::from_path(file_path)?.read_to_string(),
+        None => {
+            let file_path = if example {
+                example_input_path(day, part)
+            } else {
+                default_input_path(day)
+            };
+            AocBufReader::from_path(&file_path.to_string_lossy())?.read_to_string()
+        }
+    }
+}
+
+fn run_part(
+    day: usize,
+    solution: &dyn DynSolution,
+    part: usize,
+    input: &str,
+) -> Result<String, String> {
+    solution
+        .solve_part(input, part)
+        .map_err(|error| format!("day {}: {}", day, AocError::from(error)))?
+        .ok_or(format!("day {} has no part {}", day, part))
+}
+
+fn format_answer(day: usize, part: usize, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("day {} part {}:\n{}", day, part, answer)
+    } else {
+        format!("day {} part {}: {}", day, part, answer)
+    }
+}
+
+/// Solve the requested parts of one day, returning the lines to print
+fn run_day(day: &Day, args: &RunArgs, parts: &[usize]) -> Result<Vec<String>, String> {
+    let mut solution = day_solution(day, args.example, args.part)?;
+    for (name, value) in args.params.iter() {
+        solution
+            .set_param(name, value)
+            .map_err(|error| format!("day {}: {}", day.day, error))?;
+    }
+    let input = read_input(args.input.as_deref(), day, args.example, args.part)
+        .map_err(|error| error.to_string())?;
+
+    let mut lines: Vec<String> = Vec::new();
+    for part in parts.iter() {
+        if args.format == Format::Text {
+            let answer = run_part(day.day, solution.as_ref(), *part, &input)?;
+            lines.push(format_answer(day.day, *part, &answer));
+            continue;
+        }
+
+        let report = solution
+            .report_part(&input, *part)
+            .map_err(|error| format!("day {}: {}", day.day, AocError::from(error)))?
+            .ok_or(format!("day {} has no part {}", day.day, part))?;
+        let record = Record::new(day.day, *part, &input, report);
+        lines.push(match args.format {
+            Format::Json => output::to_json(&record),
+            Format::Csv => output::to_csv(&record),
+            Format::Text => unreachable!(),
+        });
+    }
+    Ok(lines)
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![lookup_day(args.year, day)?],
+        None => registry::days(args.year),
+    };
+    let parts: Vec<usize> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if args.format == Format::Csv {
+        println!("{}", output::CSV_HEADER);
+    }
+    // days run concurrently, but are printed in order
+    let outputs = Pool::default().map(days, |day| run_day(day, &args, &parts));
+    for output in outputs {
+        for line in output? {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// List the puzzle constants of each day, with the example's where they differ
+fn params(args: ParamsArgs) -> Result<(), String> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![lookup_day(cli::YEAR, day)?],
+        None => registry::days(cli::YEAR),
+    };
+
+    for day in days {
+        let params = day.solution(false).params();
+        let example_params = day.solution(true).params();
+        if params.is_empty() {
+            if args.day.is_some() {
+                println!("day {} has no parameters", day.day);
+            }
+            continue;
+        }
+
+        println!("day {}:", day.day);
+        for (param, example_param) in params.iter().zip(example_params.iter()) {
+            let mut line = format!("  --{:<14} {:>15}  {}", param.name, param.value, param.help);
+            if example_param.value != param.value {
+                line.push_str(&format!(" (example {})", example_param.value));
+            }
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// Check inputs have the shape their day expects, without solving them
+fn check(args: CheckArgs) -> Result<(), String> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![lookup_day(cli::YEAR, day)?],
+        None => registry::days(cli::YEAR),
+    };
+
+    let mut n_ok: usize = 0;
+    let mut n_invalid: usize = 0;
+    for day in days {
+        let label = match (&args.input, args.example) {
+            (Some(input), _) => input.clone(),
+            (None, true) => example_input_path(day, None).to_string_lossy().into_owned(),
+            (None, false) => default_input_path(day).to_string_lossy().into_owned(),
+        };
+        // not every day has an example to check
+        if args.input.is_none() && args.day.is_none() && !Path::new(&label).exists() {
+            continue;
+        }
+        let input = read_input(args.input.as_deref(), day, args.example, None)
+            .map_err(|error| format!("day {}: {}", day.day, error))?;
+
+        let diagnostics = day.solution(args.example).validate(&input);
+        if diagnostics.is_empty() {
+            n_ok += 1;
+            println!("day {} ({}): ok", day.day, label);
+        } else {
+            n_invalid += 1;
+            println!(
+                "day {} ({}): {} problems",
+                day.day,
+                label,
+                diagnostics.len()
+            );
+            for diagnostic in diagnostics {
+                println!("  {}", diagnostic.to_string().replace('\n', "\n  "));
+            }
+        }
+    }
+
+    println!("{} ok, {} with problems", n_ok, n_invalid);
+    if n_invalid == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} inputs have problems", n_invalid))
+    }
+}
+
+/// Write a random input for a day, e.g. to pipe into `aoc run --input -`
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let day = lookup_day(cli::YEAR, args.day)?;
+    let mut solution = day.solution(args.example);
+    for (name, value) in args.params.iter() {
+        solution
+            .set_param(name, value)
+            .map_err(|error| format!("day {}: {}", day.day, error))?;
+    }
+
+    let input = solution
+        .generate(&mut Rng::new(args.seed), args.size)
+        .ok_or(format!("day {} has no input generator", day.day))?;
+    match &args.output {
+        Some(file_path) => fs::write(file_path, input)
+            .map_err(|error| format!("could not write {}: {}", file_path, error)),
+        None => io::stdout()
+            .write_all(input.as_bytes())
+            .map_err(|error| format!("could not write the input: {}", error)),
+    }
+}
+
+/// Download puzzle inputs that are not cached yet
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let days: Vec<usize> = match args.day {
+        Some(day) => vec![day],
+        None => registry::days(args.year)
+            .iter()
+            .map(|day| day.day)
+            .collect(),
+    };
+    let client = Client::new(Config::load()?);
+
+    for day in days {
+        let fetched = fetch::fetch(&client, args.year, day)?;
+        let status = match fetched {
+            Fetched::Cached(_) => "cached",
+            Fetched::Downloaded(_) => "downloaded",
+        };
+        println!("day {}: {} ({})", day, status, fetched.path().display());
+    }
+    Ok(())
+}
+
+/// Start a day from the template and wire it into the workspace and runner
+fn new_day(args: NewDayArgs) -> Result<(), String> {
+    let dir = scaffold::new_day(&scaffold::src_dir(), args.year, args.day)?;
+    let dir = dir.canonicalize().unwrap_or(dir);
+    println!(
+        "created {} ({})",
+        scaffold::crate_name(args.year, args.day),
+        dir.display()
+    );
+    Ok(())
+}
+
+/// Solve a part and submit the answer, unless earlier attempts rule it out
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let day = lookup_day(args.year, args.day)?;
+            let input = read_input(args.input.as_deref(), day, false, None)
+                .map_err(|error| error.to_string())?;
+            run_part(day.day, day.solution(false).as_ref(), args.part, &input)?
+        }
+    };
+    let client = Client::new(Config::load()?);
+
+    let verdict = submit::submit(&client, args.year, args.day, args.part, &answer)?;
+    println!(
+        "day {} part {}: {}: {}",
+        args.day, args.part, answer, verdict
+    );
+    if verdict == Verdict::Correct {
+        Ok(())
+    } else {
+        Err(format!("{} was not accepted", answer))
+    }
+}
+
+/// Solve the part the answer is for, using the input it was recorded for.
+/// Answers for example inputs are solved with the example's constants and
+/// the overrides the example declares.
+fn solve_answer(answer: &Answer) -> Result<String, String> {
+    let day = lookup_day(cli::YEAR, answer.day)?;
+    let input_path = day.dir().join(&answer.input);
+    let input = AocBufReader::from_path(&input_path.to_string_lossy())
+        .and_then(|reader| reader.read_to_string())
+        .map_err(|error| error.to_string())?;
+    let mut solution = day.solution(answer.input.starts_with("inputs/example"));
+    for (name, value) in answer.params.iter() {
+        solution
+            .set_param(name, value)
+            .map_err(|error| format!("day {}: {}", day.day, error))?;
+    }
+    run_part(day.day, solution.as_ref(), answer.part, &input)
+}
+
+/// The example files a day crate has, e.g. `inputs/example_2.txt`
+fn example_files(day: &Day) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(day.dir().join("inputs"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| name.starts_with("example"))
+                .map(|name| format!("inputs/{}", name))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// List the examples each day declares, and where they fall short
+fn examples(args: ExamplesArgs) -> Result<(), String> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![lookup_day(cli::YEAR, day)?],
+        None => registry::days(cli::YEAR),
+    };
+
+    let mut uncovered: Vec<String> = Vec::new();
+    for day in days.iter() {
+        let examples = day.solution(true).examples();
+        let gaps = answers::coverage_gaps(&examples, &example_files(day));
+        let mut segments: Vec<String> = examples
+            .iter()
+            .map(|example| {
+                let parts: Vec<String> = example
+                    .answers
+                    .iter()
+                    .map(|(part, _)| format!("part {}", part))
+                    .collect();
+                format!("{} ({})", example.path, parts.join(", "))
+            })
+            .collect();
+        if !gaps.is_empty() {
+            uncovered.push(day.day.to_string());
+            segments.extend(gaps);
+        }
+        println!("day {:>2}: {}", day.day, segments.join("; "));
+    }
+
+    println!(
+        "{} of {} days are fully covered",
+        days.len() - uncovered.len(),
+        days.len()
+    );
+    if !uncovered.is_empty() {
+        println!("gaps in days {}", uncovered.join(", "));
+    }
+    Ok(())
+}
+
+/// Watch a day's simulation step by step in the terminal
+fn animate(args: AnimateArgs) -> Result<(), String> {
+    let day = lookup_day(cli::YEAR, args.day)?;
+    let input = read_input(args.input.as_deref(), day, args.example, Some(args.part))
+        .map_err(|error| format!("day {}: {}", day.day, error))?;
+    let mut scene = day_solution(day, args.example, Some(args.part))?
+        .animation(&input, args.part)
+        .map_err(|error| format!("day {}: {}", day.day, error))?
+        .ok_or(format!("day {} has no animation", day.day))?;
+
+    let title = format!("day {} part {}", day.day, args.part);
+    animate::play(
+        scene.as_mut(),
+        &title,
+        Duration::from_millis(args.delay),
+        args.steps,
+    )
+    .map_err(|error| error.to_string())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let mut answers: Vec<Answer> = answers::parse_answers(answers::ANSWERS)
+        .map_err(|error| format!("answers file: {}", error))?;
+    for day in registry::days(cli::YEAR) {
+        answers.extend(answers::from_examples(
+            day.day,
+            &day.solution(true).examples(),
+        ));
+    }
+    answers.sort_by_key(|answer| (answer.day, answer.part));
+    let answers: Vec<Answer> = answers
+        .into_iter()
+        .filter(|answer| args.day.is_none_or(|day| answer.day == day))
+        .collect();
+    if answers.is_empty() {
+        return Err("no answers to verify".to_string());
+    }
+
+    let actuals = Pool::default().map(answers.iter().collect(), solve_answer);
+
+    let mut n_passed: usize = 0;
+    let mut n_mismatched: usize = 0;
+    let mut n_failed: usize = 0;
+    for (answer, actual) in answers.iter().zip(actuals) {
+        let label = format!("day {} part {} ({})", answer.day, answer.part, answer.input);
+        match actual {
+            Ok(actual) if actual == answer.expected => {
+                n_passed += 1;
+                println!("{}: pass", label);
+            }
+            Ok(actual) => {
+                n_mismatched += 1;
+                println!(
+                    "{}: mismatch\n{}",
+                    label,
+                    answers::diff(&answer.expected, &actual)
+                );
+            }
+            Err(message) => {
+                n_failed += 1;
+                println!("{}: fail: {}", label, message);
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} mismatched, {} failed",
+        n_passed, n_mismatched, n_failed
+    );
+    if n_passed == answers.len() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} of {} answers did not verify",
+            answers.len() - n_passed,
+            answers.len()
+        ))
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline: Option<Vec<BenchResult>> = match &args.baseline {
+        Some(file_path) => {
+            let text = AocBufReader::from_path(file_path)
+                .and_then(|reader| reader.read_to_string())
+                .map_err(|error| error.to_string())?;
+            Some(bench::from_json(&text).map_err(|error| format!("{}: {}", file_path, error))?)
+        }
+        None => None,
+    };
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![lookup_day(cli::YEAR, day)?],
+        None => registry::days(cli::YEAR),
+    };
+    let threshold = args.threshold as f64 / 100.0;
+
+    let mut results: Vec<BenchResult> = Vec::new();
+    let mut n_regressions: usize = 0;
+    for day in days {
+        let solution = day.solution(false);
+        let input = read_input(None, day, false, None).map_err(|error| error.to_string())?;
+        let runs = (0..args.runs)
+            .map(|_| solution.time_stages(&input))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| format!("day {}: {}", day.day, AocError::from(error)))?;
+
+        for result in bench::bench_results(day.day, &runs) {
+            let mut line = format!(
+                "day {:>2} {:<6}  min {:>10}  median {:>10}  max {:>10}",
+                result.day,
+                result.stage,
+                bench::format_duration(result.stats.min),
+                bench::format_duration(result.stats.median),
+                bench::format_duration(result.stats.max),
+            );
+            if let Some(baseline) = &baseline {
+                if let Some(change) = bench::median_change(&result, baseline) {
+                    line.push_str(&format!("  {:+.1}%", change * 100.0));
+                }
+                if bench::is_regression(&result, baseline, threshold) {
+                    n_regressions += 1;
+                    line.push_str("  REGRESSION");
+                }
+            }
+            println!("{}", line);
+            results.push(result);
+        }
+    }
+
+    if let Some(file_path) = &args.save {
+        fs::write(file_path, bench::to_json(args.runs, &results))
+            .map_err(|error| format!("could not write {}: {}", file_path, error))?;
+    }
+    if n_regressions > 0 {
+        return Err(format!(
+            "{} stages are more than {}% slower than the baseline",
+            n_regressions, args.threshold
+        ));
+    }
+    Ok(())
+}
+
+/// Counters are reported along with info messages, so `-v` shows them
+fn report_counters() {
+    for counter in log::take_counters() {
+        shared::info!("{} {}: {}", counter.target, counter.name, counter.count);
+    }
+}
+
+fn main() -> ExitCode {
+    let command = cli::parse_log_args(std::env::args().skip(1)).and_then(|(log_args, args)| {
+        log::set_max_level(Level::from_verbosity(log_args.verbosity));
+        for (target, level) in log_args.targets {
+            log::set_target_level(&target, level);
+        }
+        cli::parse_args(args.into_iter())
+    });
+    let command = match command {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n{}", message, cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(jobs) = command.jobs() {
+        pool::set_default_jobs(jobs);
+    }
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Params(args) => params(args),
+        Command::Check(args) => check(args),
+        Command::Generate(args) => generate(args),
+        Command::Fetch(args) => fetch(args),
+        Command::NewDay(args) => new_day(args),
+        Command::Submit(args) => submit(args),
+        Command::Examples(args) => examples(args),
+        Command::Animate(args) => animate(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+    };
+    report_counters();
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use shared::solution::{Extra, Report};
+
+use crate::json::Value;
+
+/// How `aoc run` writes its answers
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Format {
+    /// `day N part P: answer`, for people
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// A header line, then one row per answer
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Everything reported about one solved part
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub input_checksum: String,
+    pub elapsed: Duration,
+    pub extras: Vec<Extra>,
+}
+
+impl Record {
+    pub fn new(day: usize, part: usize, input: &str, report: Report) -> Record {
+        Record {
+            day,
+            part,
+            answer: report.answer,
+            input_checksum: checksum(input),
+            elapsed: report.elapsed,
+            extras: report.extras,
+        }
+    }
+}
+
+/// 64 bit FNV-1a of the input, in hex. Enough to tell which input an
+/// answer came from, not meant to be cryptographically strong.
+pub fn checksum(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,input_checksum,elapsed_ns,extras";
+
+/// Quote a CSV field if it holds a separator, quote or newline
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The record as one CSV row, with the extras joined as `name=value;...`
+pub fn to_csv(record: &Record) -> String {
+    let extras: Vec<String> = record
+        .extras
+        .iter()
+        .map(|extra| format!("{}={}", extra.name, extra.value))
+        .collect();
+    [
+        record.day.to_string(),
+        record.part.to_string(),
+        csv_field(&record.answer),
+        record.input_checksum.clone(),
+        record.elapsed.as_nanos().to_string(),
+        csv_field(&extras.join(";")),
+    ]
+    .join(",")
+}
+
+/// The record as a single line of JSON. Extras that are numbers are
+/// written as numbers, integers digit for digit, answers are always strings.
+pub fn to_json(record: &Record) -> String {
+    let extras = record.extras.iter().map(|extra| {
+        let value = match (extra.value.parse::<i128>(), extra.value.parse::<f64>()) {
+            (Ok(number), _) => Value::from(number),
+            (_, Ok(number)) if number.is_finite() => Value::from(number),
+            _ => Value::from(extra.value.as_str()),
+        };
+        (extra.name, value)
+    });
+    Value::object([
+        ("day", Value::from(record.day)),
+        ("part", Value::from(record.part)),
+        ("answer", Value::from(record.answer.as_str())),
+        (
+            "input_checksum",
+            Value::from(record.input_checksum.as_str()),
+        ),
+        ("elapsed_ns", Value::from(record.elapsed.as_nanos() as u64)),
+        ("extras", Value::object(extras)),
+    ])
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    fn record() -> Record {
+        Record::new(
+            24,
+            2,
+            "#.#\n",
+            Report {
+                answer: "54".to_string(),
+                elapsed: Duration::from_micros(1500),
+                extras: vec![Extra::new("leg_1", 18), Extra::new("note", "a, b")],
+            },
+        )
+    }
+
+    #[test]
+    fn test_format_from_name() {
+        assert_eq!(Format::from_name("json"), Some(Format::Json));
+        assert_eq!(Format::from_name("csv"), Some(Format::Csv));
+        assert_eq!(Format::from_name("text"), Some(Format::Text));
+        assert_eq!(Format::from_name("xml"), None);
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("1\n2\n"), checksum("1\n2"));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&record()),
+            format!(
+                "24,2,54,{},1500000,\"leg_1=18;note=a, b\"",
+                checksum("#.#\n")
+            )
+        );
+
+        let mut multiline = record();
+        multiline.answer = "#\"#\n.#".to_string();
+        multiline.extras.clear();
+        assert!(to_csv(&multiline).starts_with("24,2,\"#\"\"#\n.#\","));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let value = json::parse(&to_json(&record())).unwrap();
+        assert_eq!(value.get("day").and_then(Value::as_f64), Some(24.0));
+        assert_eq!(value.get("answer").and_then(Value::as_str), Some("54"));
+        assert_eq!(
+            value.get("elapsed_ns").and_then(Value::as_f64),
+            Some(1_500_000.0)
+        );
+        let extras = value.get("extras").unwrap();
+        assert_eq!(extras.get("leg_1").and_then(Value::as_f64), Some(18.0));
+        assert_eq!(extras.get("note").and_then(Value::as_str), Some("a, b"));
+
+        // past 2^53, where an f64 would round it
+        let mut large = record();
+        large.extras = vec![Extra::new("height", 9_007_199_254_740_993u64)];
+        let text = to_json(&large);
+        assert!(text.contains("\"height\":9007199254740993"));
+        let extras = json::parse(&text).unwrap();
+        let height = extras.get("extras").and_then(|extras| extras.get("height"));
+        assert_eq!(height, Some(&Value::Integer(9_007_199_254_740_993)));
+    }
+}
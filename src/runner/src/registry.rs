@@ -0,0 +1,170 @@
+use std::path::PathBuf;
+
+use shared::params::Params;
+use shared::solution::DynSolution;
+
+use crate::scaffold;
+
+pub struct Day {
+    pub year: usize,
+    pub day: usize,
+    /// Build the day with its puzzle constants, or the example's
+    pub new: fn(example: bool) -> Box<dyn DynSolution>,
+}
+
+impl Day {
+    /// The day's crate, which keeps its inputs
+    pub fn dir(&self) -> PathBuf {
+        scaffold::crate_dir(&scaffold::src_dir(), self.year, self.day)
+    }
+
+    pub fn solution(&self, example: bool) -> Box<dyn DynSolution> {
+        (self.new)(example)
+    }
+}
+
+fn new_day<S: DynSolution + Params + Default + 'static>(example: bool) -> Box<dyn DynSolution> {
+    if example {
+        Box::new(S::example())
+    } else {
+        Box::new(S::default())
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        year: 2022,
+        day: 1,
+        new: new_day::<day_1::Day1>,
+    },
+    Day {
+        year: 2022,
+        day: 2,
+        new: new_day::<day_2::Day2>,
+    },
+    Day {
+        year: 2022,
+        day: 3,
+        new: new_day::<day_3::Day3>,
+    },
+    Day {
+        year: 2022,
+        day: 4,
+        new: new_day::<day_4::Day4>,
+    },
+    Day {
+        year: 2022,
+        day: 5,
+        new: new_day::<day_5::Day5>,
+    },
+    Day {
+        year: 2022,
+        day: 6,
+        new: new_day::<day_6::Day6>,
+    },
+    Day {
+        year: 2022,
+        day: 7,
+        new: new_day::<day_7::Day7>,
+    },
+    Day {
+        year: 2022,
+        day: 8,
+        new: new_day::<day_8::Day8>,
+    },
+    Day {
+        year: 2022,
+        day: 9,
+        new: new_day::<day_9::Day9>,
+    },
+    Day {
+        year: 2022,
+        day: 10,
+        new: new_day::<day_10::Day10>,
+    },
+    Day {
+        year: 2022,
+        day: 11,
+        new: new_day::<day_11::Day11>,
+    },
+    Day {
+        year: 2022,
+        day: 12,
+        new: new_day::<day_12::Day12>,
+    },
+    Day {
+        year: 2022,
+        day: 13,
+        new: new_day::<day_13::Day13>,
+    },
+    Day {
+        year: 2022,
+        day: 14,
+        new: new_day::<day_14::Day14>,
+    },
+    Day {
+        year: 2022,
+        day: 15,
+        new: new_day::<day_15::Day15>,
+    },
+    Day {
+        year: 2022,
+        day: 16,
+        new: new_day::<day_16::Day16>,
+    },
+    Day {
+        year: 2022,
+        day: 17,
+        new: new_day::<day_17::Day17>,
+    },
+    Day {
+        year: 2022,
+        day: 18,
+        new: new_day::<day_18::Day18>,
+    },
+    Day {
+        year: 2022,
+        day: 19,
+        new: new_day::<day_19::Day19>,
+    },
+    Day {
+        year: 2022,
+        day: 20,
+        new: new_day::<day_20::Day20>,
+    },
+    Day {
+        year: 2022,
+        day: 21,
+        new: new_day::<day_21::Day21>,
+    },
+    Day {
+        year: 2022,
+        day: 22,
+        new: new_day::<day_22::Day22>,
+    },
+    Day {
+        year: 2022,
+        day: 23,
+        new: new_day::<day_23::Day23>,
+    },
+    Day {
+        year: 2022,
+        day: 24,
+        new: new_day::<day_24::Day24>,
+    },
+    Day {
+        year: 2022,
+        day: 25,
+        new: new_day::<day_25::Day25>,
+    },
+];
+
+pub fn get_day(year: usize, day: usize) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|registered| registered.year == year && registered.day == day)
+}
+
+/// The registered days of a year, in order
+pub fn days(year: usize) -> Vec<&'static Day> {
+    DAYS.iter().filter(|day| day.year == year).collect()
+}
@@ -0,0 +1,359 @@
+//! Start a new day: a crate from a template, wired into the workspace and
+//! the runner. Days of the repository's own year live in `src/day_N`, days of
+//! other years in `src/yYEAR/day_N` with crates named `yYEAR_day_N`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::YEAR;
+
+/// The `src` directory of the repository, which holds every crate
+pub fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn crate_name(year: usize, day: usize) -> String {
+    if year == YEAR {
+        format!("day_{}", day)
+    } else {
+        format!("y{}_day_{}", year, day)
+    }
+}
+
+pub fn crate_dir(src_dir: &Path, year: usize, day: usize) -> PathBuf {
+    if year == YEAR {
+        src_dir.join(format!("day_{}", day))
+    } else {
+        src_dir
+            .join(format!("y{}", year))
+            .join(format!("day_{}", day))
+    }
+}
+
+/// The path from a day crate back to `src`
+fn src_relative(year: usize) -> &'static str {
+    if year == YEAR {
+        ".."
+    } else {
+        "../.."
+    }
+}
+
+fn cargo_toml(year: usize, day: usize) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+shared = {{ path = "{}/shared" }}
+"#,
+        crate_name(year, day),
+        src_relative(year)
+    )
+}
+
+fn main_rs(year: usize, day: usize) -> String {
+    format!(
+        r#"use std::fs;
+
+use {name}::Day{day};
+use shared::solution::Solution;
+
+const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/part_1.txt");
+
+fn main() {{
+    let input = fs::read_to_string(INPUT_PATH).unwrap();
+    let day = Day{day};
+    let parsed = day.parse(&input).unwrap();
+    println!("{{}}", day.part_1(&parsed));
+    println!("{{}}", day.part_2(&parsed));
+}}
+"#,
+        name = crate_name(year, day),
+        day = day
+    )
+}
+
+fn lib_rs(year: usize, day: usize) -> String {
+    format!(
+        r#"//! {year} day {day}
+
+use shared::error::ParseError;
+use shared::examples::Example;
+use shared::params::Params;
+use shared::solution::Solution;
+use shared::validate::{{check_not_empty, Diagnostic}};
+
+pub fn parse_input(reader: impl Iterator<Item = String>) -> Vec<String> {{
+    reader.collect()
+}}
+
+pub fn validate(input: &str) -> Vec<Diagnostic> {{
+    check_not_empty(input)
+}}
+
+pub fn part_1(_lines: &[String]) -> usize {{
+    0
+}}
+
+pub fn part_2(_lines: &[String]) -> usize {{
+    0
+}}
+
+#[derive(Default)]
+pub struct Day{day};
+
+impl Params for Day{day} {{}}
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(parse_input(input.lines().map(String::from)))
+    }}
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {{
+        validate(input)
+    }}
+
+    fn part_1(&self, input: &Self::Input) -> usize {{
+        part_1(input)
+    }}
+
+    fn part_2(&self, input: &Self::Input) -> usize {{
+        part_2(input)
+    }}
+
+    // TODO: replace the placeholders with the example's answers from the puzzle text
+    fn examples(&self) -> Vec<Example> {{
+        vec![Example::new("inputs/example.txt").part_1(0).part_2(0)]
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    shared::example_tests!(Day{day});
+
+    #[test]
+    fn test_validate() {{
+        let input = std::fs::read_to_string("inputs/example.txt").unwrap();
+        assert_eq!(validate(&input), vec![]);
+    }}
+}}
+"#,
+        year = year,
+        day = day
+    )
+}
+
+/// The placeholder inputs, until the real ones are pasted in
+const EXAMPLE_INPUT: &str = "paste the example from the puzzle text here\n";
+const PUZZLE_INPUT: &str = "paste the puzzle input here, or run aoc fetch\n";
+
+/// Add the glob covering a year's days to the workspace members. The
+/// repository's own year is already covered by `src/day_*`.
+pub fn add_workspace_member(manifest: &str, year: usize) -> Result<String, String> {
+    if year == YEAR {
+        return Ok(manifest.to_string());
+    }
+    let member = format!("\"src/y{}/day_*\"", year);
+    if manifest.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+    let runner = "\"src/runner\"";
+    let idx = manifest
+        .find(runner)
+        .ok_or("the workspace manifest has no src/runner member")?;
+    Ok(format!(
+        "{}{}, {}",
+        &manifest[..idx],
+        member,
+        &manifest[idx..]
+    ))
+}
+
+/// Add a day crate to the runner's dependencies, after the last day
+pub fn add_dependency(manifest: &str, year: usize, day: usize) -> Result<String, String> {
+    let name = crate_name(year, day);
+    if manifest
+        .lines()
+        .any(|line| line.starts_with(&format!("{} =", name)))
+    {
+        return Err(format!("the runner already depends on {}", name));
+    }
+    let relative = if year == YEAR {
+        format!("../day_{}", day)
+    } else {
+        format!("../y{}/day_{}", year, day)
+    };
+    let dependency = format!("{} = {{ path = \"{}\" }}\n", name, relative);
+
+    let mut lines: Vec<&str> = manifest.split_inclusive('\n').collect();
+    let idx = lines
+        .iter()
+        .rposition(|line| line.contains("day_") && line.contains("path ="))
+        .map_or(lines.len(), |idx| idx + 1);
+    lines.insert(idx, &dependency);
+    Ok(lines.concat())
+}
+
+/// Add a day to the end of the runner's registry
+pub fn register_day(registry: &str, year: usize, day: usize) -> Result<String, String> {
+    let constructor = format!("new_day::<{}::Day{}>", crate_name(year, day), day);
+    if registry.contains(&constructor) {
+        return Err(format!("{} day {} is already registered", year, day));
+    }
+    let start = registry
+        .find("pub const DAYS")
+        .ok_or("the registry has no DAYS")?;
+    let end = start
+        + registry[start..]
+            .find("\n];")
+            .ok_or("the registry's DAYS are not closed")?;
+    let entry = format!(
+        "\n    Day {{\n        year: {},\n        day: {},\n        new: {},\n    }},",
+        year, day, constructor
+    );
+    Ok(format!("{}{}{}", &registry[..end], entry, &registry[end..]))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+    }
+    fs::write(path, contents).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Create the crate of a day under `src_dir` and wire it up, returning its
+/// directory. Nothing is written unless every edit applies.
+pub fn new_day(src_dir: &Path, year: usize, day: usize) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}", day));
+    }
+    let dir = crate_dir(src_dir, year, day);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let workspace_path = src_dir.join("..").join("Cargo.toml");
+    let runner_path = src_dir.join("runner").join("Cargo.toml");
+    let registry_path = src_dir.join("runner").join("src").join("registry.rs");
+    let workspace = add_workspace_member(&read(&workspace_path)?, year)?;
+    let runner = add_dependency(&read(&runner_path)?, year, day)?;
+    let registry = register_day(&read(&registry_path)?, year, day)?;
+
+    write(&dir.join("Cargo.toml"), &cargo_toml(year, day))?;
+    write(&dir.join("src").join("lib.rs"), &lib_rs(year, day))?;
+    write(&dir.join("src").join("main.rs"), &main_rs(year, day))?;
+    write(&dir.join("inputs").join("example.txt"), EXAMPLE_INPUT)?;
+    write(&dir.join("inputs").join("part_1.txt"), PUZZLE_INPUT)?;
+    write(&workspace_path, &workspace)?;
+    write(&runner_path, &runner)?;
+    write(&registry_path, &registry)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_crate_layout() {
+        let src = Path::new("src");
+        assert_eq!(crate_name(YEAR, 6), "day_6");
+        assert_eq!(crate_dir(src, YEAR, 6), Path::new("src/day_6"));
+        assert_eq!(crate_name(2023, 1), "y2023_day_1");
+        assert_eq!(crate_dir(src, 2023, 1), Path::new("src/y2023/day_1"));
+        assert!(cargo_toml(2023, 1).contains("path = \"../../shared\""));
+        assert!(main_rs(2023, 1).contains("use y2023_day_1::Day1;"));
+        assert!(lib_rs(2023, 1).contains("impl Solution for Day1 {"));
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = "[workspace]\nmembers = [\"src/shared\", \"src/day_*\", \"src/runner\"]\n";
+        assert_eq!(add_workspace_member(manifest, YEAR).unwrap(), manifest);
+        let added = add_workspace_member(manifest, 2023).unwrap();
+        assert_eq!(
+            added,
+            "[workspace]\nmembers = [\"src/shared\", \"src/day_*\", \"src/y2023/day_*\", \"src/runner\"]\n"
+        );
+        assert_eq!(add_workspace_member(&added, 2023).unwrap(), added);
+        assert!(add_workspace_member("[workspace]\n", 2023).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\nshared = { path = \"../shared\" }\nday_1 = { path = \"../day_1\" }\n\n[dev-dependencies]\n";
+        assert_eq!(
+            add_dependency(manifest, 2023, 1).unwrap(),
+            "[dependencies]\nshared = { path = \"../shared\" }\nday_1 = { path = \"../day_1\" }\ny2023_day_1 = { path = \"../y2023/day_1\" }\n\n[dev-dependencies]\n"
+        );
+        assert!(add_dependency(manifest, YEAR, 1).is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry = include_str!("registry.rs");
+        let registered = register_day(registry, 2023, 1).unwrap();
+        assert!(registered.contains(
+            "    },\n    Day {\n        year: 2023,\n        day: 1,\n        new: new_day::<y2023_day_1::Day1>,\n    },\n];"
+        ));
+        assert!(register_day(&registered, 2023, 1).is_err());
+        assert!(register_day(registry, YEAR, 6).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let src = root.join("src");
+        write(
+            &root.join("Cargo.toml"),
+            include_str!("../../../Cargo.toml"),
+        )
+        .unwrap();
+        write(
+            &src.join("runner").join("Cargo.toml"),
+            include_str!("../Cargo.toml"),
+        )
+        .unwrap();
+        write(
+            &src.join("runner").join("src").join("registry.rs"),
+            include_str!("registry.rs"),
+        )
+        .unwrap();
+
+        let dir = new_day(&src, 2023, 1).unwrap();
+        assert_eq!(dir, src.join("y2023").join("day_1"));
+        assert!(dir.join("src").join("lib.rs").exists());
+        assert!(dir.join("inputs").join("example.txt").exists());
+        assert!(read(&root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"src/y2023/day_*\""));
+        assert!(read(&src.join("runner").join("Cargo.toml"))
+            .unwrap()
+            .contains("y2023_day_1 = { path = \"../y2023/day_1\" }"));
+
+        assert!(new_day(&src, 2023, 1)
+            .unwrap_err()
+            .contains("already exists"));
+        assert!(new_day(&src, 2023, 26).is_err());
+        // day 6 is registered, so nothing is created for it
+        assert!(new_day(&src, YEAR, 6).is_err());
+        assert!(!crate_dir(&src, YEAR, 6).exists());
+    }
+}
@@ -0,0 +1,137 @@
+//! A local HTTP server for tests, standing in for the puzzle site. It answers
+//! every request with whatever the handler returns and keeps the requests it
+//! received so that tests can check what was sent.
+
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::config::Config;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StubRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl StubRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct StubServer {
+    /// `http://127.0.0.1:PORT`, without a trailing slash
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<StubRequest>>>,
+}
+
+type Handler = dyn Fn(&StubRequest) -> (u16, String) + Send + Sync;
+
+fn read_request(stream: &mut TcpStream) -> Option<StubRequest> {
+    let mut raw: Vec<u8> = Vec::new();
+    let mut buffer = [0; 1024];
+    let head_end = loop {
+        if let Some(idx) = raw.windows(4).position(|window| window == b"\r\n\r\n") {
+            break idx;
+        }
+        let n_read = stream.read(&mut buffer).ok()?;
+        if n_read == 0 {
+            return None;
+        }
+        raw.extend_from_slice(&buffer[..n_read]);
+    };
+
+    let head = String::from_utf8_lossy(&raw[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.to_string(), value.trim().to_string()))
+        .collect();
+    let mut request = StubRequest {
+        method,
+        path,
+        headers,
+        body: String::new(),
+    };
+
+    let content_length: usize = request
+        .header("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = raw[head_end + 4..].to_vec();
+    while body.len() < content_length {
+        let n_read = stream.read(&mut buffer).ok()?;
+        if n_read == 0 {
+            break;
+        }
+        body.extend_from_slice(&buffer[..n_read]);
+    }
+    request.body = String::from_utf8_lossy(&body).into_owned();
+    Some(request)
+}
+
+impl StubServer {
+    /// Serve on a free port until the test process exits
+    pub fn start(
+        handler: impl Fn(&StubRequest) -> (u16, String) + Send + Sync + 'static,
+    ) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Arc<Mutex<Vec<StubRequest>>> = Arc::new(Mutex::new(Vec::new()));
+        let handler: Box<Handler> = Box::new(handler);
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+                let (status, body) = handler(&request);
+                received.lock().unwrap().push(request);
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        StubServer { base_url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<StubRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Settings for talking to a stub server, with a fresh cache directory named
+/// after the test and a short request interval
+pub fn test_config(base_url: &str, name: &str) -> Config {
+    let cache_dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&cache_dir);
+    Config {
+        session: Some("53616c".to_string()),
+        base_url: base_url.to_string(),
+        cache_dir,
+        user_agent: "aoc-runner tests".to_string(),
+        request_interval: Duration::from_millis(200),
+    }
+}
@@ -0,0 +1,491 @@
+//! Submitting answers. Every judged attempt is recorded in
+//! `CACHE_DIR/YEAR/attempts.txt`, so that a known wrong answer, or one that
+//! an earlier "too high" or "too low" already rules out, is never sent again.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answers::{escape, unescape};
+use crate::client::Client;
+
+/// What the site made of an answer
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint
+    Wrong,
+    /// The answer was not judged, since another was submitted too recently
+    Wait(Duration),
+    /// The part was already solved, so the answer was not judged
+    AlreadySolved,
+    /// A page that did not look like any of the above, as text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// The name used in the attempts file, for verdicts that judged the answer
+    fn record_name(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too_high"),
+            Verdict::TooLow => Some("too_low"),
+            Verdict::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_record_name(name: &str) -> Option<Verdict> {
+        match name {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "not judged, wait {}", format_wait(*wait)),
+            Verdict::AlreadySolved => write!(f, "not judged, the part is already solved"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, or of the whole page, without tags
+fn page_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// A duration such as `4m 34s` or `34s`
+fn parse_duration(text: &str) -> Option<Duration> {
+    let mut secs: u64 = 0;
+    for token in text.split_whitespace() {
+        let unit = token.chars().last()?;
+        let number = token.strip_suffix(unit)?.parse::<u64>().ok()?;
+        secs += number
+            * match unit {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// The wait asked for after a wrong answer, e.g. `please wait one minute`
+fn parse_cooldown(text: &str) -> Option<Duration> {
+    let rest = &text[text.find("wait ")? + "wait ".len()..];
+    let (amount, rest) = rest.split_once(' ')?;
+    let minutes = match amount {
+        "one" | "a" => 1,
+        amount => amount.parse::<u64>().ok()?,
+    };
+    rest.starts_with("minute")
+        .then_some(Duration::from_secs(minutes * 60))
+}
+
+/// The verdict in a response page, and how long to wait before the next
+/// answer may be sent, if the page says
+pub fn parse_response(html: &str) -> (Verdict, Option<Duration>) {
+    let text = page_text(html);
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_duration(wait))
+            .unwrap_or(Duration::from_secs(60));
+        return (Verdict::Wait(wait), Some(wait));
+    } else if text.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text.chars().take(120).collect())
+    };
+    let cooldown = parse_cooldown(&text);
+    (verdict, cooldown)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Attempt {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    /// Only verdicts that judged the answer are recorded
+    pub verdict: Verdict,
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(4, ' ');
+    Some(Attempt {
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: Verdict::from_record_name(fields.next()?)?,
+        answer: unescape(fields.next()?),
+    })
+}
+
+/// The attempts file has one `DAY PART VERDICT ANSWER` line per attempt, with
+/// the answer escaped as in the answers file
+pub fn parse_attempts(text: &str) -> Result<Vec<Attempt>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            parse_attempt(line).ok_or(format!(
+                "line {}: expected DAY PART VERDICT ANSWER",
+                idx + 1
+            ))
+        })
+        .collect()
+}
+
+fn format_attempt(attempt: &Attempt) -> Option<String> {
+    Some(format!(
+        "{} {} {} {}\n",
+        attempt.day,
+        attempt.part,
+        attempt.verdict.record_name()?,
+        escape(&attempt.answer)
+    ))
+}
+
+/// Why `answer` should not be sent, going by the earlier attempts at the same part
+pub fn known_verdict(
+    attempts: &[Attempt],
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Option<String> {
+    let attempts: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|attempt| attempt.day == day && attempt.part == part)
+        .collect();
+    if let Some(correct) = attempts
+        .iter()
+        .find(|attempt| attempt.verdict == Verdict::Correct)
+    {
+        return Some(format!(
+            "the part is already solved, with {}",
+            correct.answer
+        ));
+    }
+    if let Some(same) = attempts.iter().find(|attempt| attempt.answer == answer) {
+        return Some(format!(
+            "{} was already submitted: {}",
+            answer, same.verdict
+        ));
+    }
+
+    let number = answer.parse::<i128>().ok()?;
+    let numbers_that_were = |verdict: Verdict| {
+        attempts
+            .iter()
+            .filter(move |attempt| attempt.verdict == verdict)
+            .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+    };
+    if let Some(too_high) = numbers_that_were(Verdict::TooHigh).min() {
+        if number >= too_high {
+            return Some(format!("{} was already too high", too_high));
+        }
+    }
+    if let Some(too_low) = numbers_that_were(Verdict::TooLow).max() {
+        if number <= too_low {
+            return Some(format!("{} was already too low", too_low));
+        }
+    }
+    None
+}
+
+fn attempts_path(cache_dir: &Path, year: usize) -> PathBuf {
+    cache_dir.join(year.to_string()).join("attempts.txt")
+}
+
+/// Holds the time before which the site said not to send another answer
+fn wait_path(cache_dir: &Path) -> PathBuf {
+    cache_dir.join(".submit_wait_until")
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis()
+}
+
+/// How much longer to wait before sending an answer, as the site last asked
+fn remaining_wait(cache_dir: &Path) -> Option<Duration> {
+    let until = fs::read_to_string(wait_path(cache_dir))
+        .ok()?
+        .trim()
+        .parse::<u128>()
+        .ok()?;
+    let now = now_millis();
+    (until > now).then(|| Duration::from_millis((until - now) as u64))
+}
+
+/// Send the answer, unless earlier attempts show it is wrong or the site
+/// asked to wait. Judged answers are recorded.
+pub fn submit(
+    client: &Client,
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<Verdict, String> {
+    if answer.contains('\n') {
+        return Err(
+            "not submitting: the answer spans several lines; read it off and pass it with --answer"
+                .to_string(),
+        );
+    }
+    let cache_dir = client.config().cache_dir.clone();
+    let attempts_path = attempts_path(&cache_dir, year);
+    let attempts = match fs::read_to_string(&attempts_path) {
+        Ok(text) => parse_attempts(&text)
+            .map_err(|error| format!("{}: {}", attempts_path.display(), error))?,
+        Err(_) => Vec::new(),
+    };
+    if let Some(reason) = known_verdict(&attempts, day, part, answer) {
+        return Err(format!("not submitting: {}", reason));
+    }
+    if let Some(wait) = remaining_wait(&cache_dir) {
+        return Err(format!(
+            "not submitting: the site asked to wait another {}",
+            format_wait(wait)
+        ));
+    }
+
+    let page = client.answer(year, day, part, answer)?;
+    let (verdict, wait) = parse_response(&page);
+    if let Some(wait) = wait {
+        fs::write(
+            wait_path(&cache_dir),
+            (now_millis() + wait.as_millis()).to_string(),
+        )
+        .map_err(|error| format!("{}: {}", wait_path(&cache_dir).display(), error))?;
+    }
+
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict: verdict.clone(),
+    };
+    if let Some(line) = format_attempt(&attempt) {
+        let dir = attempts_path.parent().expect("attempts are kept per year");
+        fs::create_dir_all(dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+        let mut text = fs::read_to_string(&attempts_path).unwrap_or_default();
+        text.push_str(&line);
+        fs::write(&attempts_path, text)
+            .map_err(|error| format!("{}: {}", attempts_path.display(), error))?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::{test_config, StubServer};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    fn attempt(part: usize, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            (Verdict::Correct, None)
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, ... Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            (Verdict::TooHigh, Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too low.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.")).1,
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")).0,
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 34s left to wait.")),
+            (
+                Verdict::Wait(Duration::from_secs(274)),
+                Some(Duration::from_secs(274))
+            )
+        );
+        // a multi-byte last character is no unit, and no panic
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently.  You have 4m 3€ left to wait."
+            ))
+            .0,
+            Verdict::Wait(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .0,
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            parse_response("<html>Maintenance</html>").0,
+            Verdict::Unknown("Maintenance".to_string())
+        );
+    }
+
+    #[test]
+    fn test_attempts_round_trip() {
+        let attempts = vec![
+            attempt(1, "71300", Verdict::TooHigh),
+            attempt(2, "EHZFZHCZ", Verdict::Wrong),
+        ];
+        let text: String = attempts.iter().filter_map(format_attempt).collect();
+        assert_eq!(text, "1 1 too_high 71300\n1 2 wrong EHZFZHCZ\n");
+        assert_eq!(parse_attempts(&text), Ok(attempts));
+        assert!(parse_attempts("1 1 maybe 5\n").is_err());
+
+        let screen = attempt(2, "#..#\n#\\.#", Verdict::Wrong);
+        let text = format_attempt(&screen).unwrap();
+        assert_eq!(text, "1 2 wrong #..#\\n#\\\\.#\n");
+        assert_eq!(parse_attempts(&text), Ok(vec![screen]));
+        assert_eq!(
+            format_attempt(&attempt(1, "5", Verdict::AlreadySolved)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_known_verdict() {
+        let attempts = vec![
+            attempt(1, "500", Verdict::TooHigh),
+            attempt(1, "700", Verdict::TooHigh),
+            attempt(1, "100", Verdict::TooLow),
+            attempt(1, "300", Verdict::Wrong),
+            attempt(2, "42", Verdict::Correct),
+        ];
+        assert_eq!(known_verdict(&attempts, 1, 1, "250"), None);
+        assert_eq!(
+            known_verdict(&attempts, 1, 1, "300"),
+            Some("300 was already submitted: wrong".to_string())
+        );
+        assert_eq!(
+            known_verdict(&attempts, 1, 1, "600"),
+            Some("500 was already too high".to_string())
+        );
+        assert_eq!(
+            known_verdict(&attempts, 1, 1, "100"),
+            Some("100 was already submitted: too low".to_string())
+        );
+        assert_eq!(
+            known_verdict(&attempts, 1, 1, "99"),
+            Some("100 was already too low".to_string())
+        );
+        assert!(known_verdict(&attempts, 1, 2, "43").is_some());
+        assert_eq!(known_verdict(&attempts, 2, 1, "600"), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = StubServer::start(|request| {
+            let message = match request.body.as_str() {
+                "level=1&answer=71300" => "That's the right answer!",
+                "level=1&answer=45000" => "That's not the right answer; your answer is too low.",
+                "level=2&answer=1" => "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 2s left to wait.",
+                _ => "That's not the right answer.",
+            };
+            (200, page(message))
+        });
+        let client = Client::new(test_config(&server.base_url, "submit"));
+
+        assert_eq!(submit(&client, 2022, 1, 1, "45000"), Ok(Verdict::TooLow));
+        assert!(submit(&client, 2022, 1, 1, "45000").is_err());
+        assert!(submit(&client, 2022, 1, 1, "900").is_err());
+        assert_eq!(server.requests().len(), 1);
+
+        assert_eq!(submit(&client, 2022, 1, 1, "71300"), Ok(Verdict::Correct));
+        assert!(submit(&client, 2022, 1, 1, "71301").is_err());
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].path, "/2022/day/1/answer");
+
+        // not judged, so not recorded, but later answers wait
+        assert_eq!(
+            submit(&client, 2022, 1, 2, "1"),
+            Ok(Verdict::Wait(Duration::from_secs(2)))
+        );
+        assert!(submit(&client, 2022, 1, 2, "2")
+            .unwrap_err()
+            .contains("asked to wait"));
+        // a screen of letters is never posted
+        assert!(submit(&client, 2022, 10, 2, "#..#\n#..#")
+            .unwrap_err()
+            .contains("several lines"));
+        assert_eq!(server.requests().len(), 3);
+
+        let attempts_text =
+            fs::read_to_string(attempts_path(&client.config().cache_dir, 2022)).unwrap();
+        assert_eq!(attempts_text, "1 1 too_low 45000\n1 1 correct 71300\n");
+    }
+}
@@ -0,0 +1,358 @@
+//! Drawing a simulation on a grid as text, frame by frame, in ANSI colours.
+//! A day maps its cells to glyphs, and the runner's `aoc animate` plays the
+//! frames in the terminal through a viewport that follows the action.
+
+use crate::geom::Point2;
+use crate::sim::{Driver, Simulation};
+
+/// The 8 standard terminal colours that show up on a dark background
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    /// The SGR code that sets it as the foreground colour
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// How one cell is drawn
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Glyph {
+    pub ch: char,
+    /// The terminal's own colour if `None`
+    pub colour: Option<Colour>,
+}
+
+impl Glyph {
+    pub fn new(ch: char) -> Glyph {
+        Glyph { ch, colour: None }
+    }
+
+    pub fn coloured(ch: char, colour: Colour) -> Glyph {
+        Glyph {
+            ch,
+            colour: Some(colour),
+        }
+    }
+}
+
+/// A simulation that can be drawn on a grid
+pub trait Render {
+    /// What can be in a cell, mapped to a `Glyph` by whoever draws it
+    type Cell;
+
+    /// Every cell that isn't empty
+    fn cells(&self) -> Vec<(Point2<isize>, Self::Cell)>;
+
+    /// The area to keep in view, as its top left and bottom right corners.
+    /// Everything that is drawn, by default.
+    fn focus(&self) -> Option<(Point2<isize>, Point2<isize>)> {
+        bounds(self.cells().iter().map(|(point, _)| *point))
+    }
+}
+
+/// The top left and bottom right corners of the smallest rectangle around
+/// `points`, or `None` if there are none
+pub fn bounds(
+    points: impl IntoIterator<Item = Point2<isize>>,
+) -> Option<(Point2<isize>, Point2<isize>)> {
+    points.into_iter().fold(None, |bounds, point| match bounds {
+        None => Some((point, point)),
+        Some((min, max)) => Some((
+            Point2::new(min.x.min(point.x), min.y.min(point.y)),
+            Point2::new(max.x.max(point.x), max.y.max(point.y)),
+        )),
+    })
+}
+
+/// The part of the grid that fits on the screen
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Viewport {
+    /// The grid position shown in the top left corner
+    pub origin: Point2<isize>,
+    pub n_rows: usize,
+    pub n_cols: usize,
+}
+
+impl Viewport {
+    pub fn new(n_rows: usize, n_cols: usize) -> Viewport {
+        Viewport {
+            origin: Point2::new(0, 0),
+            n_rows,
+            n_cols,
+        }
+    }
+
+    /// Scroll as little as possible to bring the area from `min` to `max`
+    /// into view. An area too big to fit is kept in view from its top left.
+    pub fn follow(&mut self, (min, max): (Point2<isize>, Point2<isize>)) {
+        self.origin.y = follow_axis(self.origin.y, self.n_rows, min.y, max.y);
+        self.origin.x = follow_axis(self.origin.x, self.n_cols, min.x, max.x);
+    }
+}
+
+/// Where a view of `len` starting at `start` should start to show `min..=max`
+fn follow_axis(start: isize, len: usize, min: isize, max: isize) -> isize {
+    let len = len as isize;
+    if max - min + 1 > len || min < start {
+        min
+    } else if max >= start + len {
+        max - len + 1
+    } else {
+        start
+    }
+}
+
+/// Draw the cells in view, one line per row, with `empty` wherever there is
+/// no cell. Colours are reset at the end of every line.
+pub fn render_frame(cells: &[(Point2<isize>, Glyph)], viewport: &Viewport, empty: Glyph) -> String {
+    let mut glyphs = vec![vec![empty; viewport.n_cols]; viewport.n_rows];
+    for (point, glyph) in cells {
+        let row = point.y - viewport.origin.y;
+        let col = point.x - viewport.origin.x;
+        if (0..viewport.n_rows as isize).contains(&row)
+            && (0..viewport.n_cols as isize).contains(&col)
+        {
+            glyphs[row as usize][col as usize] = *glyph;
+        }
+    }
+
+    glyphs
+        .iter()
+        .map(|row| render_line(row))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn render_line(glyphs: &[Glyph]) -> String {
+    let mut line = String::new();
+    let mut colour: Option<Colour> = None;
+    for glyph in glyphs {
+        if glyph.colour != colour {
+            match glyph.colour {
+                Some(new_colour) => line.push_str(&format!("\x1b[{}m", new_colour.code())),
+                None => line.push_str("\x1b[0m"),
+            }
+            colour = glyph.colour;
+        }
+        line.push(glyph.ch);
+    }
+    if colour.is_some() {
+        line.push_str("\x1b[0m");
+    }
+    line
+}
+
+/// Object safe view of a simulation with its glyphs, so that the runner can
+/// play any day's animation
+pub trait Scene {
+    /// Take one step, returning whether there was one to take
+    fn step(&mut self) -> bool;
+
+    /// Go back one step, returning whether there was one to go back to
+    fn step_back(&mut self) -> bool;
+
+    fn n_steps(&self) -> usize;
+
+    fn is_done(&self) -> bool;
+
+    fn glyphs(&self) -> Vec<(Point2<isize>, Glyph)>;
+
+    fn focus(&self) -> Option<(Point2<isize>, Point2<isize>)>;
+}
+
+/// How many steps apart `Animated` keeps snapshots, so that stepping back
+/// replays at most this many steps
+const CHECKPOINT_STEPS: usize = 64;
+
+/// A `Scene` that steps `sim` with a `Driver` and draws its cells with `glyph`
+pub struct Animated<S: Simulation, F> {
+    driver: Driver<S>,
+    glyph: F,
+}
+
+impl<S, F> Animated<S, F>
+where
+    S: Simulation + Render,
+    F: Fn(&S::Cell) -> Glyph,
+{
+    pub fn new(sim: S, glyph: F) -> Animated<S, F> {
+        Animated {
+            driver: Driver::checkpointing(sim, CHECKPOINT_STEPS),
+            glyph,
+        }
+    }
+}
+
+impl<S, F> Scene for Animated<S, F>
+where
+    S: Simulation + Render,
+    F: Fn(&S::Cell) -> Glyph,
+{
+    fn step(&mut self) -> bool {
+        if self.driver.sim().is_done() {
+            return false;
+        }
+        self.driver.step();
+        true
+    }
+
+    fn step_back(&mut self) -> bool {
+        let n_steps = self.driver.n_steps();
+        if n_steps == 0 {
+            return false;
+        }
+        self.driver.seek(n_steps - 1);
+        true
+    }
+
+    fn n_steps(&self) -> usize {
+        self.driver.n_steps()
+    }
+
+    fn is_done(&self) -> bool {
+        self.driver.sim().is_done()
+    }
+
+    fn glyphs(&self) -> Vec<(Point2<isize>, Glyph)> {
+        self.driver
+            .sim()
+            .cells()
+            .iter()
+            .map(|(point, cell)| (*point, (self.glyph)(cell)))
+            .collect()
+    }
+
+    fn focus(&self) -> Option<(Point2<isize>, Point2<isize>)> {
+        self.driver.sim().focus()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A dot walking right along row 0 for `len` steps
+    struct Walk {
+        col: isize,
+        len: isize,
+    }
+
+    impl Simulation for Walk {
+        type Snapshot = isize;
+
+        fn step(&mut self) -> bool {
+            self.col += 1;
+            true
+        }
+
+        fn snapshot(&self) -> isize {
+            self.col
+        }
+
+        fn restore(&mut self, snapshot: &isize) {
+            self.col = *snapshot;
+        }
+
+        fn is_done(&self) -> bool {
+            self.col == self.len
+        }
+    }
+
+    impl Render for Walk {
+        type Cell = bool;
+
+        fn cells(&self) -> Vec<(Point2<isize>, bool)> {
+            vec![(Point2::new(0, 0), false), (Point2::new(self.col, 0), true)]
+        }
+    }
+
+    fn walk_glyph(is_walker: &bool) -> Glyph {
+        match is_walker {
+            true => Glyph::coloured('@', Colour::Green),
+            false => Glyph::new('#'),
+        }
+    }
+
+    #[test]
+    fn test_follow() {
+        let mut viewport = Viewport::new(3, 4);
+        viewport.follow((Point2::new(1, 1), Point2::new(2, 2)));
+        assert_eq!(viewport.origin, Point2::new(0, 0));
+        viewport.follow((Point2::new(5, 2), Point2::new(6, 4)));
+        assert_eq!(viewport.origin, Point2::new(3, 2));
+        viewport.follow((Point2::new(4, -1), Point2::new(5, 0)));
+        assert_eq!(viewport.origin, Point2::new(3, -1));
+        // too big to fit
+        viewport.follow((Point2::new(-10, -10), Point2::new(10, 10)));
+        assert_eq!(viewport.origin, Point2::new(-10, -10));
+    }
+
+    #[test]
+    fn test_render_frame() {
+        let cells = [
+            (Point2::new(1, 0), Glyph::coloured('o', Colour::Yellow)),
+            (Point2::new(2, 0), Glyph::coloured('o', Colour::Yellow)),
+            (Point2::new(0, 1), Glyph::new('#')),
+            (Point2::new(5, 5), Glyph::new('#')),
+        ];
+        let frame = render_frame(&cells, &Viewport::new(2, 4), Glyph::new('.'));
+        assert_eq!(frame, ".\x1b[33moo\x1b[0m.\n#...");
+    }
+
+    #[test]
+    fn test_animated() {
+        let mut scene = Animated::new(Walk { col: 0, len: 2 }, walk_glyph);
+        assert!(!scene.step_back());
+        assert!(scene.step());
+        assert_eq!(
+            scene.glyphs(),
+            vec![
+                (Point2::new(0, 0), Glyph::new('#')),
+                (Point2::new(1, 0), Glyph::coloured('@', Colour::Green)),
+            ]
+        );
+        assert!(scene.step());
+        assert!(scene.is_done());
+        assert!(!scene.step());
+        assert_eq!(scene.focus(), Some((Point2::new(0, 0), Point2::new(2, 0))));
+
+        assert!(scene.step_back());
+        assert_eq!(scene.n_steps(), 1);
+        assert!(!scene.is_done());
+    }
+
+    #[test]
+    fn test_step_back_from_checkpoint() {
+        let mut scene = Animated::new(Walk { col: 0, len: 1000 }, walk_glyph);
+        while scene.step() {}
+        assert_eq!(scene.driver.history().len(), 1000 / CHECKPOINT_STEPS + 1);
+
+        for n_steps in (990..1000).rev() {
+            assert!(scene.step_back());
+            assert_eq!(
+                (scene.n_steps(), scene.driver.sim().col),
+                (n_steps, n_steps as isize)
+            );
+        }
+    }
+}
@@ -12,6 +12,7 @@ pub fn char_to_usize(c: char) -> usize {
 pub mod tests {
     use super::*;
 
+    #[test]
     fn test_char_to_usize() {
         assert_eq!(char_to_usize('a'), 1);
         assert_eq!(char_to_usize('b'), 2);
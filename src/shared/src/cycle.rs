@@ -0,0 +1,211 @@
+//! Finding where a deterministic simulation starts repeating itself, so that
+//! a quantity it measures can be worked out for far more steps than could
+//! be simulated, e.g. the height of a tower of a trillion rocks.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::sim::Simulation;
+
+/// Once `start` steps have been taken, the simulation repeats every `period`
+/// steps, and the measured quantity changes by `delta` each time round.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+    pub delta: i64,
+    /// The quantity after each step from 0 up to `start + period`
+    values: Vec<i64>,
+}
+
+impl Cycle {
+    /// The quantity after `n` steps
+    pub fn value_at(&self, n: usize) -> i64 {
+        if n < self.values.len() {
+            return self.values[n];
+        }
+        let n_periods = (n - self.start) / self.period;
+        let offset = (n - self.start) % self.period;
+        self.values[self.start + offset] + n_periods as i64 * self.delta
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CycleError {
+    /// No state repeated within this many steps, or the simulation stopped
+    NotFound { steps: usize },
+    /// A state repeated, but the steps after it did not repeat as well, so
+    /// the key leaves out something that matters
+    Unverified { start: usize, period: usize },
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CycleError::NotFound { steps } => write!(f, "no cycle within {} steps", steps),
+            CycleError::Unverified { start, period } => write!(
+                f,
+                "the cycle of {} steps from step {} does not repeat",
+                period, start
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// Step `sim` until `key` repeats, taking at most `max_steps` steps. Two
+/// states with the same key must behave the same from then on; the key can
+/// leave out what doesn't matter, such as how high a tower already is.
+/// `measure` is the quantity to extrapolate.
+///
+/// The cycle is checked over a second period, in both keys and quantity, so
+/// `sim` is left `start + 2 * period` steps on.
+pub fn find_cycle<S, K>(
+    sim: &mut S,
+    key: impl Fn(&S) -> K,
+    measure: impl Fn(&S) -> i64,
+    max_steps: usize,
+) -> Result<Cycle, CycleError>
+where
+    S: Simulation,
+    K: Hash + Eq,
+{
+    let mut first_step_by_key: HashMap<K, usize> = HashMap::new();
+    let mut values: Vec<i64> = vec![measure(sim)];
+    first_step_by_key.insert(key(sim), 0);
+
+    let (start, period) = loop {
+        let step = values.len();
+        if step > max_steps || sim.is_done() {
+            return Err(CycleError::NotFound { steps: step - 1 });
+        }
+        sim.step();
+        let state_key = key(sim);
+        if let Some(&first_step) = first_step_by_key.get(&state_key) {
+            values.push(measure(sim));
+            break (first_step, step - first_step);
+        }
+        first_step_by_key.insert(state_key, step);
+        values.push(measure(sim));
+    };
+    let delta = values[start + period] - values[start];
+
+    let unverified = CycleError::Unverified { start, period };
+    for step in start + period + 1..=start + 2 * period {
+        if sim.is_done() {
+            return Err(unverified);
+        }
+        sim.step();
+        // the key first seen at the step one period back, or for the step
+        // that closed the cycle, at `start`
+        let first_step = start + (step - start) % period;
+        let repeats_key = first_step_by_key.get(&key(sim)) == Some(&first_step);
+        let repeats_value = measure(sim) - values[step - period] == delta;
+        if !repeats_key || !repeats_value {
+            return Err(unverified);
+        }
+    }
+
+    Ok(Cycle {
+        start,
+        period,
+        delta,
+        values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts through `lead_in` steps and then round a loop of `period`
+    /// steps, scoring `per_loop` points each time round
+    struct Looping {
+        step: usize,
+        lead_in: usize,
+        period: usize,
+        per_loop: i64,
+    }
+
+    impl Looping {
+        fn position(&self) -> usize {
+            if self.step < self.lead_in {
+                self.step
+            } else {
+                self.lead_in + (self.step - self.lead_in) % self.period
+            }
+        }
+
+        fn score(&self) -> i64 {
+            let lead_in_score = self.step.min(self.lead_in) as i64;
+            if self.step < self.lead_in {
+                return lead_in_score;
+            }
+            let n_loops = ((self.step - self.lead_in) / self.period) as i64;
+            lead_in_score + n_loops * self.per_loop + (self.position() - self.lead_in) as i64
+        }
+    }
+
+    impl Simulation for Looping {
+        type Snapshot = usize;
+
+        fn step(&mut self) -> bool {
+            self.step += 1;
+            true
+        }
+
+        fn snapshot(&self) -> usize {
+            self.step
+        }
+
+        fn restore(&mut self, snapshot: &usize) {
+            self.step = *snapshot;
+        }
+    }
+
+    fn looping(lead_in: usize, period: usize, per_loop: i64) -> Looping {
+        Looping {
+            step: 0,
+            lead_in,
+            period,
+            per_loop,
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let mut sim = looping(3, 4, 10);
+        let cycle = find_cycle(&mut sim, Looping::position, Looping::score, 100).unwrap();
+        assert_eq!((cycle.start, cycle.period, cycle.delta), (3, 4, 10));
+        assert_eq!(sim.step, 11);
+
+        for n in [0, 2, 5, 7, 11, 25, 1000] {
+            let mut sim = looping(3, 4, 10);
+            (0..n).for_each(|_| {
+                sim.step();
+            });
+            assert_eq!(cycle.value_at(n), sim.score(), "after {} steps", n);
+        }
+    }
+
+    #[test]
+    fn test_find_cycle_errors() {
+        let mut sim = looping(30, 4, 10);
+        assert_eq!(
+            find_cycle(&mut sim, Looping::position, Looping::score, 20),
+            Err(CycleError::NotFound { steps: 20 })
+        );
+
+        // a key that only tells odd positions from even ones repeats too early
+        let mut sim = looping(0, 4, 10);
+        assert_eq!(
+            find_cycle(&mut sim, |sim| sim.position() % 2, Looping::score, 100),
+            Err(CycleError::Unverified {
+                start: 0,
+                period: 2
+            })
+        );
+    }
+}
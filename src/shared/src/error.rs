@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The puzzle input did not have the expected shape.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number of the offending line, if the problem is tied to one
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(line: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: Some(line),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A puzzle constant could not be set.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParamError {
+    /// The day has no parameter with this name
+    Unknown(String),
+    /// The value does not parse as the parameter's type
+    Invalid { name: String, value: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "unknown parameter '{}'", name),
+            ParamError::Invalid { name, value } => {
+                write!(f, "invalid value '{}' for parameter '{}'", value, name)
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// Everything that can go wrong between locating a puzzle input and parsing it.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file does not exist
+    MissingFile(String),
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingFile(file_path) => write!(f, "input file {} not found", file_path),
+            AocError::Io(error) => write!(f, "I/O error: {}", error),
+            AocError::Parse(error) => write!(f, "parse error: {}", error),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::MissingFile(_) => None,
+            AocError::Io(error) => Some(error),
+            AocError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(error: io::Error) -> AocError {
+        AocError::Io(error)
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> AocError {
+        AocError::Parse(error)
+    }
+}
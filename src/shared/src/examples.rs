@@ -0,0 +1,253 @@
+//! The examples from the puzzle texts and their answers, declared once per
+//! day in `Solution::examples`. `example_tests!` turns the declarations into
+//! a test, and `aoc verify` checks them along with the recorded answers.
+
+use std::fmt::{self, Display};
+use std::path::Path;
+
+use crate::input::AocBufReader;
+use crate::params::Params;
+use crate::solution::{DynSolution, Solution};
+
+/// An example input with the answers the puzzle text gives for it
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Example {
+    /// Relative to the day crate, e.g. `inputs/example.txt`
+    pub path: &'static str,
+    /// Puzzle constants to set on top of the example's, as `(name, value)`
+    pub params: Vec<(&'static str, String)>,
+    /// The expected answers, as `(part, answer)`
+    pub answers: Vec<(usize, String)>,
+}
+
+impl Example {
+    pub fn new(path: &'static str) -> Example {
+        Example {
+            path,
+            params: Vec::new(),
+            answers: Vec::new(),
+        }
+    }
+
+    pub fn param(mut self, name: &'static str, value: impl Display) -> Example {
+        self.params.push((name, value.to_string()));
+        self
+    }
+
+    pub fn part_1(self, answer: impl Display) -> Example {
+        self.answer(1, answer)
+    }
+
+    pub fn part_2(self, answer: impl Display) -> Example {
+        self.answer(2, answer)
+    }
+
+    fn answer(mut self, part: usize, answer: impl Display) -> Example {
+        self.answers.push((part, answer.to_string()));
+        self
+    }
+}
+
+/// The outcome of solving one part of an example
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ExampleCheck {
+    pub path: &'static str,
+    pub part: usize,
+    pub expected: String,
+    /// The answer, or why there is none
+    pub actual: Result<String, String>,
+}
+
+impl ExampleCheck {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+}
+
+impl Display for ExampleCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} part {}: ", self.path, self.part)?;
+        match &self.actual {
+            Ok(actual) if *actual == self.expected => write!(f, "{}", actual),
+            Ok(actual) => write!(f, "expected {}, got {}", self.expected, actual),
+            Err(message) => write!(f, "expected {}, {}", self.expected, message),
+        }
+    }
+}
+
+/// Solve each part the example has an answer for. `solution` should have the
+/// example's constants; the example's own overrides are set on it here.
+pub fn check_example(
+    solution: &mut dyn DynSolution,
+    dir: &Path,
+    example: &Example,
+) -> Vec<ExampleCheck> {
+    let input = example
+        .params
+        .iter()
+        .try_for_each(|(name, value)| solution.set_param(name, value))
+        .map_err(|error| error.to_string())
+        .and_then(|_| {
+            AocBufReader::from_path(&dir.join(example.path).to_string_lossy())
+                .and_then(|reader| reader.read_to_string())
+                .map_err(|error| error.to_string())
+        });
+
+    example
+        .answers
+        .iter()
+        .map(|(part, expected)| ExampleCheck {
+            path: example.path,
+            part: *part,
+            expected: expected.clone(),
+            actual: input.clone().and_then(|input| {
+                solution
+                    .solve_part(&input, *part)
+                    .map_err(|error| error.to_string())?
+                    .ok_or(format!("there is no part {}", part))
+            }),
+        })
+        .collect()
+}
+
+/// Check every example a day declares, panicking with the failures. `dir` is
+/// the day crate, which example paths are relative to.
+pub fn assert_examples<S: Solution + Params + Default>(dir: &str) {
+    let examples = Solution::examples(&S::example());
+    assert!(!examples.is_empty(), "no examples are declared");
+
+    let failures: Vec<String> = examples
+        .iter()
+        .flat_map(|example| check_example(&mut S::example(), Path::new(dir), example))
+        .filter(|check| !check.passed())
+        .map(|check| check.to_string())
+        .collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// A test of the examples declared by a day's `Solution::examples`, for the
+/// day's tests module, e.g. `shared::example_tests!(Day15);`
+#[macro_export]
+macro_rules! example_tests {
+    ($day:ty) => {
+        #[test]
+        fn test_examples() {
+            $crate::examples::assert_examples::<$day>(env!("CARGO_MANIFEST_DIR"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    use crate::error::{ParamError, ParseError};
+    use crate::params::{parse_value, Param};
+
+    #[derive(Default)]
+    struct Scaled {
+        factor: usize,
+    }
+
+    impl Params for Scaled {
+        fn example() -> Scaled {
+            Scaled { factor: 2 }
+        }
+
+        fn params(&self) -> Vec<Param> {
+            vec![Param::new("factor", self.factor, "what to scale by")]
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+            match name {
+                "factor" => self.factor = parse_value(name, value)?,
+                _ => return Err(ParamError::Unknown(name.to_string())),
+            }
+            Ok(())
+        }
+    }
+
+    impl Solution for Scaled {
+        type Input = Vec<usize>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(idx, line)| {
+                    line.parse::<usize>()
+                        .map_err(|_| ParseError::at_line(idx + 1, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part_1(&self, input: &Vec<usize>) -> usize {
+            input.iter().sum::<usize>() * self.factor
+        }
+
+        fn part_2(&self, input: &Vec<usize>) -> usize {
+            input.iter().product::<usize>() * self.factor
+        }
+
+        fn examples(&self) -> Vec<Example> {
+            vec![
+                Example::new("example.txt").part_1(12).part_2(12),
+                Example::new("example.txt").param("factor", 10).part_1(60),
+            ]
+        }
+    }
+
+    fn example_dir(name: &str) -> String {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("example.txt"), "1\n2\n3\n").unwrap();
+        dir.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_check_example() {
+        let dir = example_dir("check");
+        let examples = Solution::examples(&Scaled::example());
+        let checks = check_example(&mut Scaled::example(), Path::new(&dir), &examples[1]);
+        assert_eq!(
+            checks,
+            vec![ExampleCheck {
+                path: "example.txt",
+                part: 1,
+                expected: "60".to_string(),
+                actual: Ok("60".to_string()),
+            }]
+        );
+
+        let wrong = Example::new("example.txt").part_1(13).part_2(1);
+        let checks = check_example(&mut Scaled::example(), Path::new(&dir), &wrong);
+        assert!(!checks[0].passed());
+        assert_eq!(
+            checks[0].to_string(),
+            "example.txt part 1: expected 13, got 12"
+        );
+        assert!(!checks[1].passed());
+
+        let missing = Example::new("missing.txt").part_1(1);
+        assert!(!check_example(&mut Scaled::example(), Path::new(&dir), &missing)[0].passed());
+        let unknown = Example::new("example.txt").param("offset", 1).part_1(12);
+        assert!(!check_example(&mut Scaled::example(), Path::new(&dir), &unknown)[0].passed());
+    }
+
+    #[test]
+    fn test_assert_examples() {
+        assert_examples::<Scaled>(&example_dir("assert"));
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1: expected 12")]
+    fn test_assert_examples_failure() {
+        let dir = example_dir("failure");
+        fs::write(Path::new(&dir).join("example.txt"), "1\n2\n4\n").unwrap();
+        assert_examples::<Scaled>(&dir);
+    }
+}
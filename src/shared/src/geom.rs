@@ -0,0 +1,488 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position in the plane. `Direction::Up` increases `y`, whereas on a
+/// `Grid`, where `y` is the row, up is towards lower `y`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A displacement between two `Point2`s
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A position in space
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// A displacement between two `Point3`s
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Vector3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// `|a - b|`, which also works for unsigned types
+fn abs_diff<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// -1, 0 or 1, according to the sign of `value`
+fn signum<T: Copy + PartialOrd + From<i8>>(value: T) -> T {
+    let zero = T::from(0);
+    if value > zero {
+        T::from(1)
+    } else if value < zero {
+        T::from(-1)
+    } else {
+        zero
+    }
+}
+
+impl<T> Point2<T> {
+    pub fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point2<T> {
+    pub fn manhattan_distance(&self, other: &Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The number of king moves between two points
+    pub fn chebyshev_distance(&self, other: &Point2<T>) -> T {
+        max(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Copy + From<i8> + Add<Output = T>> Point2<T> {
+    /// The 4 orthogonal neighbours, in the order of `Direction::ALL`
+    pub fn neighbors_4(&self) -> [Point2<T>; 4] {
+        Direction::ALL.map(|direction| *self + direction.to_vector())
+    }
+
+    /// All 8 neighbours, in the order of `Direction8::ALL`
+    pub fn neighbors_8(&self) -> [Point2<T>; 8] {
+        Direction8::ALL.map(|direction| *self + direction.to_vector())
+    }
+}
+
+impl<T> Vector2<T> {
+    pub fn new(x: T, y: T) -> Vector2<T> {
+        Vector2 { x, y }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Vector2<T> {
+    /// Rotate by 90° counter-clockwise
+    pub fn rotate_left(&self) -> Vector2<T> {
+        Vector2::new(-self.y, self.x)
+    }
+
+    /// Rotate by 90° clockwise
+    pub fn rotate_right(&self) -> Vector2<T> {
+        Vector2::new(self.y, -self.x)
+    }
+}
+
+impl<T: Copy + PartialOrd + From<i8> + Add<Output = T> + Sub<Output = T>> Vector2<T> {
+    pub fn manhattan_length(&self) -> T {
+        let zero = T::from(0);
+        abs_diff(self.x, zero) + abs_diff(self.y, zero)
+    }
+
+    pub fn chebyshev_length(&self) -> T {
+        let zero = T::from(0);
+        max(abs_diff(self.x, zero), abs_diff(self.y, zero))
+    }
+
+    /// The vector with each component replaced by its sign, i.e. a single
+    /// (possibly diagonal) step in the same general direction
+    pub fn signum(&self) -> Vector2<T> {
+        Vector2::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point3<T> {
+    pub fn manhattan_distance(&self, other: &Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Point3<T>) -> T {
+        max(
+            max(abs_diff(self.x, other.x), abs_diff(self.y, other.y)),
+            abs_diff(self.z, other.z),
+        )
+    }
+}
+
+impl<T: Copy + From<i8> + Add<Output = T>> Point3<T> {
+    /// The 6 neighbours sharing a face: +x, -x, +y, -y, +z, -z
+    pub fn neighbors_6(&self) -> [Point3<T>; 6] {
+        let one = T::from(1);
+        let minus_one = T::from(-1);
+        let zero = T::from(0);
+        [
+            Vector3::new(one, zero, zero),
+            Vector3::new(minus_one, zero, zero),
+            Vector3::new(zero, one, zero),
+            Vector3::new(zero, minus_one, zero),
+            Vector3::new(zero, zero, one),
+            Vector3::new(zero, zero, minus_one),
+        ]
+        .map(|vector| *self + vector)
+    }
+}
+
+impl<T> Vector3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vector3<T> {
+        Vector3 { x, y, z }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Vector3<T> {
+    /// Rotate by 90° counter-clockwise around the x axis
+    pub fn rotate_x(&self) -> Vector3<T> {
+        Vector3::new(self.x, -self.z, self.y)
+    }
+
+    /// Rotate by 90° counter-clockwise around the y axis
+    pub fn rotate_y(&self) -> Vector3<T> {
+        Vector3::new(self.z, self.y, -self.x)
+    }
+
+    /// Rotate by 90° counter-clockwise around the z axis
+    pub fn rotate_z(&self) -> Vector3<T> {
+        Vector3::new(-self.y, self.x, self.z)
+    }
+}
+
+impl<T: Copy + PartialOrd + From<i8> + Add<Output = T> + Sub<Output = T>> Vector3<T> {
+    pub fn manhattan_length(&self) -> T {
+        let zero = T::from(0);
+        abs_diff(self.x, zero) + abs_diff(self.y, zero) + abs_diff(self.z, zero)
+    }
+
+    pub fn chebyshev_length(&self) -> T {
+        let zero = T::from(0);
+        max(
+            max(abs_diff(self.x, zero), abs_diff(self.y, zero)),
+            abs_diff(self.z, zero),
+        )
+    }
+}
+
+/// Implements the arithmetic between a point type and its vector type:
+/// point ± vector, point - point, vector ± vector, -vector and vector * scalar
+macro_rules! impl_point_ops {
+    ($point:ident, $vector:ident, $($field:ident),+) => {
+        impl<T: Add<Output = T>> Add<$vector<T>> for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, other: $vector<T>) -> $point<T> {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub<$vector<T>> for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, other: $vector<T>) -> $point<T> {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = $vector<T>;
+
+            fn sub(self, other: $point<T>) -> $vector<T> {
+                $vector { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign<$vector<T>> for $point<T> {
+            fn add_assign(&mut self, other: $vector<T>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign<$vector<T>> for $point<T> {
+            fn sub_assign(&mut self, other: $vector<T>) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $vector<T> {
+            type Output = $vector<T>;
+
+            fn add(self, other: $vector<T>) -> $vector<T> {
+                $vector { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vector<T> {
+            type Output = $vector<T>;
+
+            fn sub(self, other: $vector<T>) -> $vector<T> {
+                $vector { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vector<T> {
+            type Output = $vector<T>;
+
+            fn neg(self) -> $vector<T> {
+                $vector { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $vector<T> {
+            type Output = $vector<T>;
+
+            fn mul(self, scalar: T) -> $vector<T> {
+                $vector { $($field: self.$field * scalar),+ }
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2, Vector2, x, y);
+impl_point_ops!(Point3, Vector3, x, y, z);
+
+/// One of the 4 orthogonal directions in the plane
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parse `U`/`R`/`D`/`L` or an arrow: `^`/`>`/`v`/`<`
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    /// A unit step in this direction, with `y` growing upwards
+    pub fn to_vector<T: From<i8>>(self) -> Vector2<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, 1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+        };
+        Vector2::new(T::from(x), T::from(y))
+    }
+}
+
+/// One of the 8 compass directions, including the diagonals
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from `North`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// A unit step in this direction, with `y` growing northwards
+    pub fn to_vector<T: From<i8>>(self) -> Vector2<T> {
+        let (x, y) = match self {
+            Direction8::North => (0, 1),
+            Direction8::NorthEast => (1, 1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, -1),
+            Direction8::South => (0, -1),
+            Direction8::SouthWest => (-1, -1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, 1),
+        };
+        Vector2::new(T::from(x), T::from(y))
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::Up => Direction8::North,
+            Direction::Right => Direction8::East,
+            Direction::Down => Direction8::South,
+            Direction::Left => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let point = Point2::new(3, -2);
+        let vector = Vector2::new(1, 4);
+        assert_eq!(point + vector, Point2::new(4, 2));
+        assert_eq!(point - vector, Point2::new(2, -6));
+        assert_eq!(Point2::new(4, 2) - point, vector);
+        assert_eq!(vector + vector, vector * 2);
+        assert_eq!(-vector, Vector2::new(-1, -4));
+
+        let mut point = Point3::new(1, 2, 3);
+        point += Vector3::new(1, 1, 1);
+        assert_eq!(point, Point3::new(2, 3, 4));
+        point -= Vector3::new(2, 3, 4);
+        assert_eq!(point, Point3::default());
+    }
+
+    #[test]
+    fn test_distances() {
+        let start = Point2::new(2, 18);
+        let end = Point2::new(-2, 15);
+        assert_eq!(start.manhattan_distance(&end), 7);
+        assert_eq!(start.chebyshev_distance(&end), 4);
+        assert_eq!((end - start).manhattan_length(), 7);
+        assert_eq!((end - start).chebyshev_length(), 4);
+        assert_eq!((end - start).signum(), Vector2::new(-1, -1));
+
+        // unsigned coordinates work too
+        let start: Point2<usize> = Point2::new(5, 1);
+        assert_eq!(start.manhattan_distance(&Point2::new(1, 5)), 8);
+
+        let start = Point3::new(1, 1, 1);
+        let end = Point3::new(2, -1, 4);
+        assert_eq!(start.manhattan_distance(&end), 6);
+        assert_eq!(start.chebyshev_distance(&end), 3);
+        assert_eq!((end - start).manhattan_length(), 6);
+        assert_eq!((end - start).chebyshev_length(), 3);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let vector = Vector2::new(2, 1);
+        assert_eq!(vector.rotate_left(), Vector2::new(-1, 2));
+        assert_eq!(vector.rotate_right(), Vector2::new(1, -2));
+        assert_eq!(vector.rotate_left().rotate_right(), vector);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.turn_left().to_vector::<i32>(),
+                direction.to_vector::<i32>().rotate_left()
+            );
+            assert_eq!(
+                direction.turn_right().to_vector::<i32>(),
+                direction.to_vector::<i32>().rotate_right()
+            );
+            assert_eq!(
+                direction.opposite().to_vector::<i32>(),
+                -direction.to_vector::<i32>()
+            );
+        }
+
+        let vector = Vector3::new(1, 2, 3);
+        assert_eq!(vector.rotate_z(), Vector3::new(-2, 1, 3));
+        assert_eq!(vector.rotate_x(), Vector3::new(1, -3, 2));
+        assert_eq!(vector.rotate_y(), Vector3::new(3, 2, -1));
+        assert_eq!(vector.rotate_x().rotate_x().rotate_x().rotate_x(), vector);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('<'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction8::from(Direction::Right), Direction8::East);
+
+        let point: Point2<i64> = Point2::new(0, 0);
+        assert_eq!(
+            point.neighbors_4(),
+            [
+                Point2::new(0, 1),
+                Point2::new(1, 0),
+                Point2::new(0, -1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert_eq!(point.neighbors_8().len(), 8);
+        assert!(!point.neighbors_8().contains(&point));
+        assert_eq!(Point3::new(0, 0, 0).neighbors_6()[3], Point3::new(0, -1, 0));
+    }
+}
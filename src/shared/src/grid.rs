@@ -0,0 +1,386 @@
+//! Dense and sparse grids, indexed with `Point2`s: `x` is the column and `y`
+//! the row, growing downwards.
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::geom::Point2;
+
+/// Offsets of the 4 orthogonal neighbours: up, down, left, right
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of all 8 neighbours, row by row
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A dense, rectangular grid, stored row by row.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    n_rows: usize,
+    n_cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(n_rows: usize, n_cols: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; n_rows * n_cols],
+            n_rows,
+            n_cols,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map_or(0, |row| row.len());
+        let mut cells: Vec<T> = Vec::with_capacity(n_rows * n_cols);
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            if row.len() != n_cols {
+                return Err(ParseError::at_line(
+                    row_idx + 1,
+                    format!("expected {} columns, found {}", n_cols, row.len()),
+                ));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            n_rows,
+            n_cols,
+        })
+    }
+
+    /// Build a grid from lines of characters, one cell per character.
+    /// `parse_char` returns `None` for characters that are not allowed.
+    pub fn parse<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+        mut parse_char: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (row_idx, line) in lines.into_iter().enumerate() {
+            let row = line
+                .as_ref()
+                .chars()
+                .enumerate()
+                .map(|(col_idx, c)| {
+                    parse_char(c).ok_or(ParseError::at_line(
+                        row_idx + 1,
+                        format!("unexpected character '{}' in column {}", c, col_idx + 1),
+                    ))
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    pub fn contains(&self, point: Point2<usize>) -> bool {
+        point.y < self.n_rows && point.x < self.n_cols
+    }
+
+    pub fn get(&self, point: Point2<usize>) -> Option<&T> {
+        match self.contains(point) {
+            true => Some(&self.cells[point.y * self.n_cols + point.x]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point2<usize>) -> Option<&mut T> {
+        match self.contains(point) {
+            true => Some(&mut self.cells[point.y * self.n_cols + point.x]),
+            false => None,
+        }
+    }
+
+    /// The point shifted by the given offset, if it is still on the grid
+    pub fn offset(&self, point: Point2<usize>, drow: isize, dcol: isize) -> Option<Point2<usize>> {
+        let row = point.y.checked_add_signed(drow)?;
+        let col = point.x.checked_add_signed(dcol)?;
+        Some(Point2 { x: col, y: row }).filter(|shifted| self.contains(*shifted))
+    }
+
+    /// Orthogonal neighbours on the grid, in the order up, down, left, right
+    pub fn neighbors_4(&self, point: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |(drow, dcol)| self.offset(point, *drow, *dcol))
+    }
+
+    /// Orthogonal and diagonal neighbours on the grid
+    pub fn neighbors_8(&self, point: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |(drow, dcol)| self.offset(point, *drow, *dcol))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.n_cols..(row + 1) * self.n_cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.n_cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.n_cols)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.n_cols.max(1))
+    }
+
+    /// Every point on the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2<usize>> + '_ {
+        (0..self.n_rows)
+            .flat_map(move |row| (0..self.n_cols).map(move |col| Point2 { x: col, y: row }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> + '_ {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, row by row, whose value matches the predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point2<usize>> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+        }
+    }
+
+    /// Draw the grid as newline-separated rows, one character per cell
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&to_char).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+/// A grid without fixed bounds, for when only a few cells are occupied
+/// or the coordinates can become negative.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<isize>, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: &Point2<isize>) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn get(&self, point: &Point2<isize>) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point2<isize>) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn insert(&mut self, point: Point2<isize>, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Point2<isize>) -> Option<T> {
+        self.cells.remove(point)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point2<isize>, &T)> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = &Point2<isize>> + '_ {
+        self.cells.keys()
+    }
+
+    /// Smallest and largest row and column of the occupied cells,
+    /// as the top left and bottom right corners
+    pub fn bounds(&self) -> Option<(Point2<isize>, Point2<isize>)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point2::new(min.x.min(point.x), min.y.min(point.y)),
+                Point2::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+
+    /// Orthogonal neighbours, occupied or not, in the order up, down, left, right
+    pub fn neighbors_4(point: Point2<isize>) -> impl Iterator<Item = Point2<isize>> {
+        OFFSETS_4
+            .iter()
+            .map(move |(drow, dcol)| Point2::new(point.x + dcol, point.y + drow))
+    }
+
+    /// Orthogonal and diagonal neighbours, occupied or not
+    pub fn neighbors_8(point: Point2<isize>) -> impl Iterator<Item = Point2<isize>> {
+        OFFSETS_8
+            .iter()
+            .map(move |(drow, dcol)| Point2::new(point.x + dcol, point.y + drow))
+    }
+
+    /// Draw the bounding box of the occupied cells, using `empty` for the other cells
+    pub fn render(&self, empty: char, to_char: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|row| {
+                (min.x..=max.x)
+                    .map(|col| match self.cells.get(&Point2::new(col, row)) {
+                        Some(value) => to_char(value),
+                        None => empty,
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point2<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2<isize>, T)>>(iter: I) -> SparseGrid<T> {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        Grid::parse(["123", "456"], |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!(grid.n_rows(), 2);
+        assert_eq!(grid.n_cols(), 3);
+        assert_eq!(grid[Point2::new(2, 1)], 6);
+        assert_eq!(grid.get(Point2::new(0, 2)), None);
+
+        assert_eq!(
+            Grid::parse(["12", "3x"], |c| c.to_digit(10)),
+            Err(ParseError::at_line(
+                2,
+                "unexpected character 'x' in column 2"
+            ))
+        );
+        assert_eq!(
+            Grid::parse(["12", "3"], |c| c.to_digit(10)),
+            Err(ParseError::at_line(2, "expected 2 columns, found 1"))
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbors_4(Point2::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point2::new(0, 1), Point2::new(1, 0)]
+        );
+        assert_eq!(grid.neighbors_4(Point2::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors_8(Point2::new(1, 0)).count(), 5);
+        assert_eq!(
+            grid.offset(Point2::new(1, 1), 0, 1),
+            Some(Point2::new(2, 1))
+        );
+        assert_eq!(grid.offset(Point2::new(1, 1), 0, 2), None);
+    }
+
+    #[test]
+    fn test_views() {
+        let mut grid = example();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u8>>(), vec![2, 5]);
+        assert_eq!(grid.position(|value| *value > 4), Some(Point2::new(1, 1)));
+
+        grid[Point2::new(0, 0)] = 9;
+        assert_eq!(grid.render(|value| (b'0' + value) as char), "923\n456");
+        assert_eq!(
+            grid.map(|value| value % 2 == 0).row(0),
+            &[false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point2::new(2, -1), 'a');
+        grid.insert(Point2::new(0, 1), 'b');
+        assert!(grid.contains(&Point2::new(0, 1)));
+        assert_eq!(grid.bounds(), Some((Point2::new(0, -1), Point2::new(2, 1))));
+        assert_eq!(grid.render('.', |c| *c), "..a\n...\nb..");
+        assert_eq!(grid.remove(&Point2::new(0, 1)), Some('b'));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(
+            SparseGrid::<char>::neighbors_8(Point2::new(0, 0)).count(),
+            8
+        );
+    }
+}
@@ -1,36 +1,226 @@
 use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use std::io::{self, BufRead, BufReader, Lines, StdinLock};
+use std::vec;
 
-fn open_file(file_path: &str) -> File {
-    File::open(file_path).unwrap()
+use crate::error::{AocError, ParseError};
+
+fn open_file(file_path: &str) -> Result<File, AocError> {
+    File::open(file_path).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound => AocError::MissingFile(file_path.to_string()),
+        _ => AocError::Io(error),
+    })
 }
 
-pub struct AocBufReader {
-    iter: Lines<BufReader<File>>,
+/// Reads a puzzle input line by line from any buffered source,
+/// e.g. a file, stdin or text embedded in the binary.
+pub struct AocBufReader<R: BufRead = BufReader<File>> {
+    iter: Lines<R>,
+    line_number: usize,
 }
 
-impl AocBufReader {
-    fn from_file(file_handle: File) -> AocBufReader {
+impl<R: BufRead> AocBufReader<R> {
+    pub fn new(reader: R) -> AocBufReader<R> {
         AocBufReader {
-            iter: BufReader::new(file_handle).lines(),
+            iter: reader.lines(),
+            line_number: 0,
         }
     }
 
-    pub fn from_string(file_path: &str) -> AocBufReader {
-        AocBufReader::from_file(open_file(file_path))
+    /// Read all remaining lines, joined by newlines
+    pub fn read_to_string(self) -> Result<String, AocError> {
+        Ok(self.collect::<Result<Vec<String>, AocError>>()?.join("\n"))
+    }
+
+    /// Read all remaining lines, grouped by blank lines
+    pub fn paragraphs(self) -> Result<Paragraphs<vec::IntoIter<String>>, AocError> {
+        Ok(self
+            .collect::<Result<Vec<String>, AocError>>()?
+            .into_iter()
+            .paragraphs())
+    }
+
+    /// Read all remaining lines, split at the first blank line
+    pub fn split_at_blank(self) -> Result<(Vec<String>, Vec<String>), AocError> {
+        Ok(self
+            .collect::<Result<Vec<String>, AocError>>()?
+            .into_iter()
+            .split_at_blank())
+    }
+}
+
+impl AocBufReader<BufReader<File>> {
+    pub fn from_path(file_path: &str) -> Result<AocBufReader<BufReader<File>>, AocError> {
+        Ok(AocBufReader::new(BufReader::new(open_file(file_path)?)))
+    }
+}
+
+impl<'a> AocBufReader<&'a [u8]> {
+    /// Read the puzzle input itself, e.g. an example embedded in a test.
+    /// `FromStr` does not fit, since the reader borrows the input.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> AocBufReader<&'a [u8]> {
+        AocBufReader::new(input.as_bytes())
+    }
+
+    /// Read raw bytes, e.g. an input embedded with `include_bytes!`
+    pub fn from_bytes(input: &'a [u8]) -> AocBufReader<&'a [u8]> {
+        AocBufReader::new(input)
+    }
+}
+
+impl AocBufReader<StdinLock<'static>> {
+    pub fn from_stdin() -> AocBufReader<StdinLock<'static>> {
+        AocBufReader::new(io::stdin().lock())
+    }
+}
+
+impl<R: BufRead> Iterator for AocBufReader<R> {
+    type Item = Result<String, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.iter.next()?;
+        self.line_number += 1;
+        let line_number = self.line_number;
+        Some(result.map_err(|error| match error.kind() {
+            io::ErrorKind::InvalidData => {
+                AocError::Parse(ParseError::at_line(line_number, "line is not valid UTF-8"))
+            }
+            _ => AocError::Io(error),
+        }))
     }
 }
 
-impl Iterator for AocBufReader {
-    type Item = String;
+/// Whether a line separates groups: empty, or nothing but whitespace
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Groups of consecutive non-blank lines. Runs of blank lines
+/// count as a single separator.
+pub struct Paragraphs<I> {
+    lines: I,
+}
+
+impl<I: Iterator<Item = String>> Iterator for Paragraphs<I> {
+    type Item = Vec<String>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            Some(result) => match result {
-                Ok(line) => Some(line),
-                Err(error) => panic!("{}", error),
-            },
-            None => None,
+        let mut paragraph: Vec<String> = Vec::new();
+        for line in self.lines.by_ref() {
+            if !is_blank(&line) {
+                paragraph.push(line);
+            } else if !paragraph.is_empty() {
+                return Some(paragraph);
+            }
         }
+
+        if paragraph.is_empty() {
+            None
+        } else {
+            Some(paragraph)
+        }
+    }
+}
+
+/// Blank line handling for anything that yields input lines,
+/// such as the lines handed to a day's `parse_input`.
+pub trait LineGroups: Iterator<Item = String> + Sized {
+    fn paragraphs(self) -> Paragraphs<Self> {
+        Paragraphs { lines: self }
+    }
+
+    /// The lines before the first blank line, and the lines after it
+    fn split_at_blank(mut self) -> (Vec<String>, Vec<String>) {
+        let header: Vec<String> = self.by_ref().take_while(|line| !is_blank(line)).collect();
+        (header, self.collect())
+    }
+}
+
+impl<I: Iterator<Item = String>> LineGroups for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file() {
+        match AocBufReader::from_path("does/not/exist.txt") {
+            Err(AocError::MissingFile(file_path)) => assert_eq!(file_path, "does/not/exist.txt"),
+            _ => panic!("expected a missing file error"),
+        }
+    }
+
+    #[test]
+    fn test_from_path() {
+        let reader = AocBufReader::from_path("Cargo.toml").unwrap();
+        assert_eq!(reader.map(Result::unwrap).next().unwrap(), "[package]");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let lines: Vec<String> = AocBufReader::from_str("1\n\n2\n")
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["1", "", "2"]);
+
+        assert_eq!(
+            AocBufReader::from_str("1\r\n\r\n2\r\n")
+                .read_to_string()
+                .unwrap(),
+            "1\n\n2"
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut reader = AocBufReader::from_bytes(b"fine\n\xff\n");
+        assert_eq!(reader.next().unwrap().unwrap(), "fine");
+        match reader.next() {
+            Some(Err(AocError::Parse(error))) => assert_eq!(error.line, Some(2)),
+            _ => panic!("expected a parse error"),
+        }
+
+        // reading past the end counts no further lines
+        assert!(reader.next().is_none());
+        assert!(reader.next().is_none());
+        assert_eq!(reader.line_number, 2);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let paragraphs: Vec<Vec<String>> = AocBufReader::from_str("1\n2\n\n3\n\n\n4\n5\n\n")
+            .paragraphs()
+            .unwrap()
+            .collect();
+        assert_eq!(paragraphs, vec![vec!["1", "2"], vec!["3"], vec!["4", "5"]]);
+
+        // a line of spaces is blank too, as for `validate::paragraphs`
+        let paragraphs: Vec<Vec<String>> = "1\n  \n2\n"
+            .lines()
+            .map(String::from)
+            .paragraphs()
+            .collect();
+        assert_eq!(paragraphs, vec![vec!["1"], vec!["2"]]);
+
+        assert_eq!(AocBufReader::from_str("").paragraphs().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_split_at_blank() {
+        let (header, body) = AocBufReader::from_str("a\nb\n\nc\n\nd")
+            .split_at_blank()
+            .unwrap();
+        assert_eq!(header, vec!["a", "b"]);
+        assert_eq!(body, vec!["c", "", "d"]);
+
+        let (header, body) = "a\n \nb".lines().map(String::from).split_at_blank();
+        assert_eq!(
+            (header, body),
+            (vec!["a".to_string()], vec!["b".to_string()])
+        );
+
+        let (header, body) = "a\nb".lines().map(String::from).split_at_blank();
+        assert_eq!(header, vec!["a", "b"]);
+        assert!(body.is_empty());
     }
 }
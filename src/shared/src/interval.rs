@@ -0,0 +1,300 @@
+use std::cmp;
+use std::ops::{Add, Sub};
+use std::slice;
+
+/// Integer types that intervals can be built from
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + From<u8> {}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>> Integer for T {}
+
+/// The integers from `min` to `max`, both included
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// Panics if `min` is greater than `max`, since intervals are never empty
+    pub fn new(min: T, max: T) -> Interval<T> {
+        assert!(min <= max, "interval must not be empty");
+        Interval { min, max }
+    }
+
+    /// The interval holding only `point`
+    pub fn point(point: T) -> Interval<T> {
+        Interval {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The number of integers in the interval
+    pub fn length(&self) -> T {
+        self.max - self.min + T::from(1)
+    }
+
+    pub fn contains(&self, point: T) -> bool {
+        self.min <= point && point <= self.max
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.min <= other.max && other.min <= self.max
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) {
+            Some(Interval {
+                min: cmp::max(self.min, other.min),
+                max: cmp::min(self.max, other.max),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Whether this interval ends before `other` starts, with a gap between them
+    fn is_separate_before(&self, other: &Interval<T>) -> bool {
+        self.max < other.min && other.min - self.max > T::from(1)
+    }
+}
+
+/// A set of integers stored as intervals. The intervals are always kept
+/// sorted, and overlapping or adjacent intervals are merged, so each
+/// set has exactly one representation.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set
+    pub fn total_length(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::from(0), |total, interval| total + interval.length())
+    }
+
+    /// The disjoint intervals making up the set, in increasing order
+    pub fn iter(&self) -> slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn contains(&self, point: T) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.max < point);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(point))
+    }
+
+    /// Add all integers in `new` to the set
+    pub fn insert(&mut self, mut new: Interval<T>) {
+        let mut merged_intervals: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len() + 1);
+        let mut inserted = false;
+        for interval in self.intervals.drain(..) {
+            if interval.is_separate_before(&new) {
+                merged_intervals.push(interval);
+            } else if new.is_separate_before(&interval) {
+                if !inserted {
+                    merged_intervals.push(new);
+                    inserted = true;
+                }
+                merged_intervals.push(interval);
+            } else {
+                new = Interval {
+                    min: cmp::min(new.min, interval.min),
+                    max: cmp::max(new.max, interval.max),
+                };
+            }
+        }
+        if !inserted {
+            merged_intervals.push(new);
+        }
+        self.intervals = merged_intervals;
+    }
+
+    /// Remove all integers in `other` from the set
+    pub fn remove(&mut self, other: Interval<T>) {
+        let mut differenced_intervals: Vec<Interval<T>> =
+            Vec::with_capacity(self.intervals.len() + 1);
+        for interval in self.intervals.drain(..) {
+            if !interval.overlaps(&other) {
+                differenced_intervals.push(interval);
+                continue;
+            }
+            if interval.min < other.min {
+                differenced_intervals.push(Interval {
+                    min: interval.min,
+                    max: other.min - T::from(1),
+                });
+            }
+            if other.max < interval.max {
+                differenced_intervals.push(Interval {
+                    min: other.max + T::from(1),
+                    max: interval.max,
+                });
+            }
+        }
+        self.intervals = differenced_intervals;
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for interval in other.iter() {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // both sets are sorted, so walk through them side by side
+        let mut intervals: Vec<Interval<T>> = Vec::new();
+        let (mut idx, mut other_idx) = (0, 0);
+        while idx < self.intervals.len() && other_idx < other.intervals.len() {
+            let interval = &self.intervals[idx];
+            let other_interval = &other.intervals[other_idx];
+            if let Some(intersection) = interval.intersection(other_interval) {
+                intervals.push(intersection);
+            }
+            if interval.max < other_interval.max {
+                idx += 1;
+            } else {
+                other_idx += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(*interval);
+        }
+        difference
+    }
+
+    /// The integers within `bounds` that are not in the set
+    pub fn complement(&self, bounds: Interval<T>) -> IntervalSet<T> {
+        IntervalSet::from(bounds).difference(self)
+    }
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> slice::Iter<'a, Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|(min, max)| Interval::new(*min, *max))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter()
+            .map(|interval| (interval.min, interval.max))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(2, 4);
+        assert_eq!(interval.length(), 3);
+        assert!(interval.contains(4));
+        assert!(!interval.contains(5));
+        assert!(interval.contains_interval(&Interval::point(3)));
+        assert!(interval.overlaps(&Interval::new(4, 8)));
+        assert!(!interval.overlaps(&Interval::new(5, 8)));
+        assert_eq!(
+            interval.intersection(&Interval::new(3, 8)),
+            Some(Interval::new(3, 4))
+        );
+        assert_eq!(interval.intersection(&Interval::new(6, 8)), None);
+    }
+
+    #[test]
+    fn test_normalized() {
+        // overlapping and adjacent intervals merge, in any insertion order
+        let set = set(&[(10, 12), (1, 3), (4, 5), (2, 2), (7, 8), (8, 9)]);
+        assert_eq!(intervals(&set), vec![(1, 5), (7, 12)]);
+        assert_eq!(set.total_length(), 11);
+        assert_eq!(set, super::tests::set(&[(7, 12), (1, 5)]));
+
+        assert!(set.contains(5));
+        assert!(!set.contains(6));
+        assert!(set.contains(7));
+        assert!(!set.contains(13));
+        assert!(IntervalSet::<i32>::new().is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+
+        assert_eq!(intervals(&a.union(&b)), vec![(0, 30)]);
+        assert_eq!(intervals(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+        assert_eq!(intervals(&a.difference(&b)), vec![(0, 4), (26, 30)]);
+        assert_eq!(intervals(&b.difference(&a)), vec![(11, 19)]);
+        assert_eq!(
+            intervals(&a.complement(Interval::new(-5, 35))),
+            vec![(-5, -1), (11, 19), (31, 35)]
+        );
+        assert!(a.complement(Interval::new(0, 10)).is_empty());
+
+        let mut c = a.clone();
+        c.remove(Interval::point(5));
+        assert_eq!(intervals(&c), vec![(0, 4), (6, 10), (20, 30)]);
+        c.insert(Interval::point(5));
+        assert_eq!(c, a);
+    }
+}
@@ -1,2 +1,17 @@
+pub mod animate;
 pub mod conversion;
+pub mod cycle;
+pub mod error;
+pub mod examples;
+pub mod generate;
+pub mod geom;
+pub mod grid;
 pub mod input;
+pub mod interval;
+pub mod log;
+pub mod params;
+pub mod pool;
+pub mod search;
+pub mod sim;
+pub mod solution;
+pub mod validate;
@@ -0,0 +1,231 @@
+//! A small logging facade. Messages go to stderr so that they never mix with
+//! answers on stdout, and are tagged with a target, the module that logged
+//! them (e.g. `day_19`). Use the `error!`, `warn!`, `info!`, `debug!` and
+//! `trace!` macros to log, and `count!` to add to a named counter.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Level> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The level shown by default is `Warn`. Each `-v` shows one more level
+    /// and `-q` only shows errors.
+    pub fn from_verbosity(verbosity: i8) -> Level {
+        let idx = (Level::Warn as i8 + verbosity).clamp(1, Level::Trace as i8) - 1;
+        Level::ALL[idx as usize]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name().to_uppercase())
+    }
+}
+
+/// The most verbose level shown for targets without a level of their own
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Levels set for single targets, overriding `MAX_LEVEL`
+static TARGET_LEVELS: Mutex<Option<HashMap<String, Level>>> = Mutex::new(None);
+
+/// Lets `enabled` skip the lock while no target has a level of its own
+static HAS_TARGET_LEVELS: AtomicBool = AtomicBool::new(false);
+
+static COUNTERS: Mutex<BTreeMap<(String, &'static str), u64>> = Mutex::new(BTreeMap::new());
+
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Show messages from `target` up to `level`, whatever the global level
+pub fn set_target_level(target: &str, level: Level) {
+    TARGET_LEVELS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(target.to_string(), level);
+    HAS_TARGET_LEVELS.store(true, Ordering::Relaxed);
+}
+
+/// Whether a message at `level` from `target` would be shown. Checked by the
+/// macros before formatting anything.
+pub fn enabled(level: Level, target: &str) -> bool {
+    if HAS_TARGET_LEVELS.load(Ordering::Relaxed) {
+        if let Some(target_level) = TARGET_LEVELS
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|target_levels| target_levels.get(target))
+        {
+            return level <= *target_level;
+        }
+    }
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+#[doc(hidden)]
+pub fn log(level: Level, target: &str, args: fmt::Arguments<'_>) {
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+/// Add `n` to the counter `name` of `target`
+pub fn add_count(target: &str, name: &'static str, n: u64) {
+    *COUNTERS
+        .lock()
+        .unwrap()
+        .entry((target.to_string(), name))
+        .or_insert(0) += n;
+}
+
+/// A counter's total, as reported at the end of a run
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Counter {
+    pub target: String,
+    pub name: &'static str,
+    pub count: u64,
+}
+
+/// All counters so far, sorted by target and name, resetting them to zero
+pub fn take_counters() -> Vec<Counter> {
+    std::mem::take(&mut *COUNTERS.lock().unwrap())
+        .into_iter()
+        .map(|((target, name), count)| Counter {
+            target,
+            name,
+            count,
+        })
+        .collect()
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if $crate::log::enabled(level, module_path!()) {
+            $crate::log::log(level, module_path!(), format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+/// Add to a counter of the calling module. Counters are shared between
+/// threads, so count locally in hot loops and add the total once.
+#[macro_export]
+macro_rules! count {
+    ($name:expr) => {
+        $crate::log::add_count(module_path!(), $name, 1)
+    };
+    ($name:expr, $n:expr) => {
+        $crate::log::add_count(module_path!(), $name, $n as u64)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(1), Level::Info);
+        assert_eq!(Level::from_verbosity(5), Level::Trace);
+        assert_eq!(Level::from_verbosity(-1), Level::Error);
+        assert_eq!(Level::from_verbosity(-3), Level::Error);
+        assert_eq!(Level::from_name("DEBUG"), Some(Level::Debug));
+        assert_eq!(Level::from_name("loud"), None);
+        assert!(Level::Error < Level::Trace);
+    }
+
+    #[test]
+    fn test_target_levels() {
+        set_target_level("shared::log::tests::quiet", Level::Error);
+        set_target_level("shared::log::tests::loud", Level::Trace);
+        assert!(!enabled(Level::Warn, "shared::log::tests::quiet"));
+        assert!(enabled(Level::Trace, "shared::log::tests::loud"));
+        assert!(enabled(Level::Error, "shared::log::tests::other"));
+    }
+
+    #[test]
+    fn test_counters() {
+        count!("explored", 3);
+        count!("explored");
+        count!("pruned", 2usize);
+        let counters: Vec<Counter> = take_counters()
+            .into_iter()
+            .filter(|counter| counter.target == module_path!())
+            .collect();
+        assert_eq!(
+            counters,
+            vec![
+                Counter {
+                    target: module_path!().to_string(),
+                    name: "explored",
+                    count: 4,
+                },
+                Counter {
+                    target: module_path!().to_string(),
+                    name: "pruned",
+                    count: 2,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::ParamError;
+
+/// A puzzle constant that can be changed from the command line
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Param {
+    pub name: &'static str,
+    /// The current value, as it would be written on the command line
+    pub value: String,
+    pub help: &'static str,
+}
+
+impl Param {
+    pub fn new(name: &'static str, value: impl Display, help: &'static str) -> Param {
+        Param {
+            name,
+            value: value.to_string(),
+            help,
+        }
+    }
+}
+
+/// Puzzle constants of a day, such as the number of rounds to play. Days
+/// without any only need an empty `impl Params for DayN {}`.
+pub trait Params {
+    /// The constants for `inputs/example.txt`, which often differ from the real puzzle's
+    fn example() -> Self
+    where
+        Self: Sized + Default,
+    {
+        Self::default()
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Set the constant called `name`, parsing `value`
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown(name.to_string()))
+    }
+}
+
+/// Parse the value of a parameter, for use in `Params::set_param`
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    value.parse::<T>().map_err(|_| ParamError::Invalid {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Rounds {
+        n_rounds: usize,
+    }
+
+    impl Params for Rounds {
+        fn example() -> Rounds {
+            Rounds { n_rounds: 3 }
+        }
+
+        fn params(&self) -> Vec<Param> {
+            vec![Param::new("rounds", self.n_rounds, "rounds to play")]
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+            match name {
+                "rounds" => self.n_rounds = parse_value(name, value)?,
+                _ => return Err(ParamError::Unknown(name.to_string())),
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct NoParams;
+
+    impl Params for NoParams {}
+
+    #[test]
+    fn test_params() {
+        let mut rounds = Rounds::example();
+        assert_eq!(rounds.params()[0].value, "3");
+        rounds.set_param("rounds", "20").unwrap();
+        assert_eq!(rounds.n_rounds, 20);
+        assert_eq!(
+            rounds.set_param("rounds", "twenty"),
+            Err(ParamError::Invalid {
+                name: "rounds".to_string(),
+                value: "twenty".to_string()
+            })
+        );
+        assert_eq!(
+            rounds.set_param("laps", "1"),
+            Err(ParamError::Unknown("laps".to_string()))
+        );
+
+        let mut no_params = NoParams::example();
+        assert!(no_params.params().is_empty());
+        assert!(no_params.set_param("rounds", "1").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// How many threads `Pool::default()` uses. Set once by the runner's `--jobs` flag.
+static DEFAULT_JOBS: AtomicUsize = AtomicUsize::new(1);
+
+pub fn set_default_jobs(n_jobs: usize) {
+    DEFAULT_JOBS.store(n_jobs.max(1), Ordering::Relaxed);
+}
+
+pub fn default_jobs() -> usize {
+    DEFAULT_JOBS.load(Ordering::Relaxed)
+}
+
+thread_local! {
+    /// How many threads a pool worker may use for pools of its own: its
+    /// share of the jobs of the pool it belongs to. `None` outside pools.
+    static WORKER_JOBS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Runs independent pieces of work on up to `n_jobs` scoped threads. Results
+/// always come back in the order of the items, however the work was scheduled.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Pool {
+    n_jobs: usize,
+}
+
+impl Pool {
+    /// A pool of a single job runs everything on the calling thread
+    pub fn new(n_jobs: usize) -> Pool {
+        Pool {
+            n_jobs: n_jobs.max(1),
+        }
+    }
+
+    pub fn n_jobs(&self) -> usize {
+        self.n_jobs
+    }
+
+    /// Run `work` as one of `n_workers` workers, so that pools it opens
+    /// share out this pool's jobs rather than adding to them
+    fn as_worker(&self, n_workers: usize, work: impl FnOnce()) {
+        WORKER_JOBS.with(|jobs| jobs.set(Some(self.n_jobs / n_workers)));
+        work();
+    }
+
+    /// Apply `f` to every item, returning the results in the order of the items
+    pub fn map<T, R, F>(&self, items: Vec<T>, f: F) -> Vec<R>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync,
+    {
+        let n_items = items.len();
+        if self.n_jobs == 1 || n_items <= 1 {
+            return items.into_iter().map(f).collect();
+        }
+
+        let queue = Mutex::new(items.into_iter().enumerate());
+        let results: Mutex<Vec<Option<R>>> = Mutex::new((0..n_items).map(|_| None).collect());
+        let n_workers = self.n_jobs.min(n_items);
+        thread::scope(|scope| {
+            for _ in 0..n_workers {
+                scope.spawn(|| {
+                    self.as_worker(n_workers, || loop {
+                        let next = queue.lock().unwrap().next();
+                        let Some((idx, item)) = next else {
+                            break;
+                        };
+                        let result = f(item);
+                        results.lock().unwrap()[idx] = Some(result);
+                    })
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|result| result.expect("every item is processed"))
+            .collect()
+    }
+
+    /// The first item, in the order of the items, for which `f` returns
+    /// something. Items after one that already matched are skipped.
+    pub fn find_map_first<T, R, F>(&self, items: Vec<T>, f: F) -> Option<R>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> Option<R> + Sync,
+    {
+        if self.n_jobs == 1 {
+            return items.into_iter().find_map(f);
+        }
+
+        let n_workers = self.n_jobs.min(items.len());
+        let queue = Mutex::new(items.into_iter().enumerate());
+        let first_found = AtomicUsize::new(usize::MAX);
+        let found: Mutex<Vec<(usize, R)>> = Mutex::new(Vec::new());
+        thread::scope(|scope| {
+            for _ in 0..n_workers {
+                scope.spawn(|| {
+                    self.as_worker(n_workers, || loop {
+                        let next = queue.lock().unwrap().next();
+                        let Some((idx, item)) = next else {
+                            break;
+                        };
+                        // items are handed out in order, so everything left comes later
+                        if idx > first_found.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        if let Some(result) = f(item) {
+                            first_found.fetch_min(idx, Ordering::Relaxed);
+                            found.lock().unwrap().push((idx, result));
+                        }
+                    })
+                });
+            }
+        });
+
+        found
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .min_by_key(|(idx, _)| *idx)
+            .map(|(_, result)| result)
+    }
+}
+
+/// A pool of `default_jobs()` threads, or inside a pool worker, of the
+/// worker's share of them, so that nested pools never add up to more
+impl Default for Pool {
+    fn default() -> Pool {
+        Pool::new(WORKER_JOBS.with(Cell::get).unwrap_or_else(default_jobs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map_keeps_order() {
+        for n_jobs in [1, 2, 8] {
+            let pool = Pool::new(n_jobs);
+            // later items finish first
+            let squares = pool.map((0..10u64).collect(), |n| {
+                thread::sleep(Duration::from_millis(10 - n));
+                n * n
+            });
+            assert_eq!(squares, (0..10u64).map(|n| n * n).collect::<Vec<u64>>());
+        }
+        assert!(Pool::new(4).map(Vec::<u8>::new(), |n| n).is_empty());
+    }
+
+    #[test]
+    fn test_find_map_first() {
+        for n_jobs in [1, 3] {
+            let pool = Pool::new(n_jobs);
+            let first_even_square =
+                pool.find_map_first(vec![3, 5, 4, 7, 6], |n| (n % 2 == 0).then_some(n * n));
+            assert_eq!(first_even_square, Some(16));
+            assert_eq!(
+                pool.find_map_first(vec![1, 3], |n| (n > 5).then_some(n)),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(Pool::new(0).n_jobs(), 1);
+        assert_eq!(Pool::new(4).n_jobs(), 4);
+    }
+
+    #[test]
+    fn test_nested_pools_share_jobs() {
+        let inner_jobs = |_| Pool::default().n_jobs();
+        assert_eq!(Pool::new(4).map(vec![0, 1], inner_jobs), vec![2, 2]);
+        assert_eq!(
+            Pool::new(4).map(vec![0, 1, 2, 3, 4], inner_jobs),
+            vec![1; 5]
+        );
+        assert_eq!(
+            Pool::new(6).find_map_first(vec![0, 1], |n| (n == 1).then(|| inner_jobs(n))),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest way found from one of the start nodes to a goal node.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// The nodes visited, from the start node up to and including the goal
+    pub nodes: Vec<N>,
+}
+
+/// Every node reached by a search, and how it was first reached.
+/// Nodes are stored once and referred to by index.
+struct SearchTree<N, C> {
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> SearchTree<N, C> {
+    fn new() -> SearchTree<N, C> {
+        SearchTree {
+            nodes: Vec::new(),
+            parents: Vec::new(),
+            costs: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    fn push(&mut self, node: N, parent: Option<usize>, cost: C) -> usize {
+        let idx = self.nodes.len();
+        self.indices.insert(node.clone(), idx);
+        self.nodes.push(node);
+        self.parents.push(parent);
+        self.costs.push(cost);
+        idx
+    }
+
+    fn path_to(&self, idx: usize) -> Path<N, C> {
+        let mut nodes: Vec<N> = Vec::new();
+        let mut current = Some(idx);
+        while let Some(current_idx) = current {
+            nodes.push(self.nodes[current_idx].clone());
+            current = self.parents[current_idx];
+        }
+        nodes.reverse();
+
+        Path {
+            cost: self.costs[idx],
+            nodes,
+        }
+    }
+}
+
+/// Breadth-first search where every step costs 1. The search starts from
+/// all of `starts` at once, and stops at the first node satisfying `is_goal`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree: SearchTree<N, usize> = SearchTree::new();
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for start in starts {
+        if !tree.indices.contains_key(&start) {
+            queue.push_back(tree.push(start, None, 0));
+        }
+    }
+
+    while let Some(idx) = queue.pop_front() {
+        if is_goal(&tree.nodes[idx]) {
+            return Some(tree.path_to(idx));
+        }
+
+        let cost = tree.costs[idx] + 1;
+        for next in successors(&tree.nodes[idx]) {
+            if !tree.indices.contains_key(&next) {
+                queue.push_back(tree.push(next, Some(idx), cost));
+            }
+        }
+    }
+
+    None
+}
+
+/// The number of steps from the nearest of `starts` to every reachable node
+pub fn bfs_distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances: HashMap<N, usize> = HashMap::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Dijkstra's algorithm. `successors` yields each neighbour together with
+/// the (non-negative) cost of stepping to it. `C::default()` is taken as zero.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal, and must
+/// never overestimate it for the returned path to be the cheapest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree: SearchTree<N, C> = SearchTree::new();
+    // entries are (estimated total cost, cost so far, node index), so stale
+    // entries for nodes that have since been reached more cheaply can be skipped
+    let mut heap: BinaryHeap<Reverse<(C, C, usize)>> = BinaryHeap::new();
+    for start in starts {
+        if !tree.indices.contains_key(&start) {
+            let estimate = heuristic(&start);
+            let idx = tree.push(start, None, C::default());
+            heap.push(Reverse((estimate, C::default(), idx)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        if cost > tree.costs[idx] {
+            continue;
+        }
+        if is_goal(&tree.nodes[idx]) {
+            return Some(tree.path_to(idx));
+        }
+
+        for (next, step_cost) in successors(&tree.nodes[idx]) {
+            let next_cost = cost + step_cost;
+            let next_idx = match tree.indices.get(&next) {
+                Some(&next_idx) => {
+                    if tree.costs[next_idx] <= next_cost {
+                        continue;
+                    }
+                    tree.costs[next_idx] = next_cost;
+                    tree.parents[next_idx] = Some(idx);
+                    next_idx
+                }
+                None => tree.push(next, Some(idx), next_cost),
+            };
+            let estimate = heuristic(&tree.nodes[next_idx]);
+            heap.push(Reverse((next_cost + estimate, next_cost, next_idx)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph:
+    ///
+    ///   a -1- b -1- c
+    ///   |           |
+    ///   5           1
+    ///   |           |
+    ///   d ----1---- e
+    fn weighted_successors(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('e', 1)],
+            'd' => vec![('a', 5), ('e', 1)],
+            'e' => vec![('c', 1), ('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted_successors(node: &char) -> Vec<char> {
+        weighted_successors(node)
+            .into_iter()
+            .map(|(next, _)| next)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(['a'], unweighted_successors, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, vec!['a', 'd']);
+
+        let path = bfs(['a', 'e'], unweighted_successors, |node| *node == 'c').unwrap();
+        assert_eq!(path.nodes, vec!['e', 'c']);
+
+        assert_eq!(bfs(['a'], unweighted_successors, |node| *node == 'z'), None);
+
+        let distances = bfs_distances(['a'], unweighted_successors);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&'e'], 2);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(['a'], weighted_successors, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes, vec!['a', 'b', 'c', 'e', 'd']);
+
+        let path = dijkstra(['a', 'd'], weighted_successors, |node| *node == 'e').unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, vec!['d', 'e']);
+    }
+
+    #[test]
+    fn test_astar() {
+        // walk along a line of integers from 0 to 10, in steps of +1 or +3
+        let successors = |n: &i32| [(n + 1, 1), (n + 3, 1)];
+        let path = astar([0], successors, |n| ((10 - n).max(0) + 2) / 3, |n| *n == 10).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&10));
+    }
+}
@@ -0,0 +1,254 @@
+//! Discrete-time simulations, such as falling sand or moving elves, and a
+//! driver that steps them: a number of steps, until they stop, until nothing
+//! changes any more, or to a given step and back again.
+
+/// A simulation that advances one step at a time
+pub trait Simulation {
+    /// Everything `restore` needs to put the simulation back as it was
+    type Snapshot;
+
+    /// Advance by one step, returning whether anything changed
+    fn step(&mut self) -> bool;
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
+    /// Whether the simulation has stopped, e.g. because its input ran out.
+    /// A stopped simulation is not stepped any further. Never, by default.
+    fn is_done(&self) -> bool {
+        false
+    }
+}
+
+/// Steps a simulation and counts the steps taken. Seeking back restores the
+/// nearest snapshot and replays the steps from there, so simulations must be
+/// deterministic.
+pub struct Driver<S: Simulation> {
+    sim: S,
+    n_steps: usize,
+    initial: S::Snapshot,
+    /// The snapshot after every `every` steps, starting with step 0, if
+    /// recording
+    history: Option<Vec<S::Snapshot>>,
+    every: usize,
+}
+
+impl<S: Simulation> Driver<S> {
+    pub fn new(sim: S) -> Driver<S> {
+        Driver {
+            initial: sim.snapshot(),
+            sim,
+            n_steps: 0,
+            history: None,
+            every: 1,
+        }
+    }
+
+    /// Keep a snapshot of every step, for `history` and for seeking back
+    /// without replaying
+    pub fn recording(sim: S) -> Driver<S> {
+        Driver::checkpointing(sim, 1)
+    }
+
+    /// Keep a snapshot of every `every` steps, so that seeking back replays
+    /// fewer than `every` steps without keeping them all
+    pub fn checkpointing(sim: S, every: usize) -> Driver<S> {
+        let mut driver = Driver::new(sim);
+        driver.history = Some(vec![driver.sim.snapshot()]);
+        driver.every = every.max(1);
+        driver
+    }
+
+    pub fn sim(&self) -> &S {
+        &self.sim
+    }
+
+    pub fn into_sim(self) -> S {
+        self.sim
+    }
+
+    /// How many steps have been taken since the start
+    pub fn n_steps(&self) -> usize {
+        self.n_steps
+    }
+
+    /// The snapshot after each step so far, starting with step 0, or after
+    /// every checkpoint. Empty unless recording.
+    pub fn history(&self) -> &[S::Snapshot] {
+        self.history.as_deref().unwrap_or(&[])
+    }
+
+    /// Take one step unless the simulation is done, returning whether
+    /// anything changed
+    pub fn step(&mut self) -> bool {
+        if self.sim.is_done() {
+            return false;
+        }
+        let changed = self.sim.step();
+        self.n_steps += 1;
+        if let Some(history) = &mut self.history {
+            if self.n_steps.is_multiple_of(self.every) {
+                history.push(self.sim.snapshot());
+            }
+        }
+        changed
+    }
+
+    /// Take up to `n` steps, fewer if the simulation stops first. Returns
+    /// the number of steps taken.
+    pub fn run(&mut self, n: usize) -> usize {
+        let start = self.n_steps;
+        for _ in 0..n {
+            if self.sim.is_done() {
+                break;
+            }
+            self.step();
+        }
+        self.n_steps - start
+    }
+
+    /// Step until the simulation stops, returning the total number of steps.
+    /// Never returns for a simulation that does not stop.
+    pub fn run_until_done(&mut self) -> usize {
+        while !self.sim.is_done() {
+            self.step();
+        }
+        self.n_steps
+    }
+
+    /// Step until a step changes nothing, or the simulation stops. Returns
+    /// the total number of steps, including the one that changed nothing.
+    pub fn run_until_fixed_point(&mut self) -> usize {
+        while !self.sim.is_done() && self.step() {}
+        self.n_steps
+    }
+
+    /// Go to step `k`, forwards or back. Stops short if the simulation is
+    /// done before reaching it.
+    pub fn seek(&mut self, k: usize) {
+        if k < self.n_steps {
+            match &mut self.history {
+                Some(history) => {
+                    let checkpoint = k / self.every;
+                    self.sim.restore(&history[checkpoint]);
+                    history.truncate(checkpoint + 1);
+                    self.n_steps = checkpoint * self.every;
+                }
+                None => {
+                    self.sim.restore(&self.initial);
+                    self.n_steps = 0;
+                }
+            }
+        }
+        self.run(k - self.n_steps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Halves a number until it reaches 1, stopping early at `stop_at`
+    struct Halving {
+        value: u64,
+        stop_at: u64,
+    }
+
+    impl Simulation for Halving {
+        type Snapshot = u64;
+
+        fn step(&mut self) -> bool {
+            let previous = self.value;
+            self.value = (self.value / 2).max(1);
+            self.value != previous
+        }
+
+        fn snapshot(&self) -> u64 {
+            self.value
+        }
+
+        fn restore(&mut self, snapshot: &u64) {
+            self.value = *snapshot;
+        }
+
+        fn is_done(&self) -> bool {
+            self.value == self.stop_at
+        }
+    }
+
+    fn halving(value: u64, stop_at: u64) -> Halving {
+        Halving { value, stop_at }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut driver = Driver::new(halving(100, 0));
+        assert_eq!(driver.run(3), 3);
+        assert_eq!(driver.sim().value, 12);
+        assert_eq!(driver.n_steps(), 3);
+        assert!(driver.history().is_empty());
+
+        // stops early once done
+        let mut driver = Driver::new(halving(100, 25));
+        assert_eq!(driver.run(10), 2);
+        assert!(!driver.step());
+        assert_eq!(driver.run_until_done(), 2);
+    }
+
+    #[test]
+    fn test_run_until_fixed_point() {
+        let mut driver = Driver::new(halving(100, 0));
+        // 50, 25, 12, 6, 3, 1 and then a step that changes nothing
+        assert_eq!(driver.run_until_fixed_point(), 7);
+        assert_eq!(driver.into_sim().value, 1);
+
+        let mut driver = Driver::new(halving(100, 12));
+        assert_eq!(driver.run_until_fixed_point(), 3);
+    }
+
+    #[test]
+    fn test_history() {
+        let mut driver = Driver::recording(halving(100, 0));
+        driver.run(4);
+        assert_eq!(driver.history(), &[100, 50, 25, 12, 6]);
+
+        driver.seek(2);
+        assert_eq!((driver.n_steps(), driver.sim().value), (2, 25));
+        assert_eq!(driver.history(), &[100, 50, 25]);
+        driver.seek(5);
+        assert_eq!((driver.n_steps(), driver.sim().value), (5, 3));
+        assert_eq!(driver.history().len(), 6);
+    }
+
+    #[test]
+    fn test_checkpoints() {
+        let mut driver = Driver::checkpointing(halving(1000, 0), 2);
+        driver.run(5);
+        assert_eq!(driver.history(), &[1000, 250, 62]);
+
+        // back to the checkpoint at step 2, then one step on
+        driver.seek(3);
+        assert_eq!((driver.n_steps(), driver.sim().value), (3, 125));
+        assert_eq!(driver.history(), &[1000, 250]);
+        driver.seek(5);
+        assert_eq!((driver.n_steps(), driver.sim().value), (5, 31));
+        assert_eq!(driver.history(), &[1000, 250, 62]);
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut driver = Driver::new(halving(100, 0));
+        driver.seek(4);
+        assert_eq!(driver.sim().value, 6);
+        // back again, by replaying from the start
+        driver.seek(1);
+        assert_eq!((driver.n_steps(), driver.sim().value), (1, 50));
+        driver.seek(1);
+        assert_eq!(driver.n_steps(), 1);
+
+        let mut driver = Driver::new(halving(100, 25));
+        driver.seek(10);
+        assert_eq!(driver.n_steps(), 2);
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::animate::Scene;
+use crate::error::{ParamError, ParseError};
+use crate::examples::Example;
+use crate::generate::Rng;
+use crate::params::{Param, Params};
+use crate::validate::Diagnostic;
+
+/// The interface every day implements, so that tooling such as the runner
+/// can be written once for all of them.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse the full puzzle input
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Check that the input has the expected shape without solving it, so
+    /// that malformed input is reported rather than making `parse` panic
+    fn validate(&self, _input: &str) -> Vec<Diagnostic> {
+        Vec::new()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Self::Answer1;
+
+    fn part_2(&self, input: &Self::Input) -> Self::Answer2;
+
+    /// Solve part 1 along with facts about how it was solved that are worth
+    /// reporting besides the answer, such as a detected cycle length. Days
+    /// with extras override this to collect them from the same solve; most
+    /// days have none.
+    fn part_1_with_extras(&self, input: &Self::Input) -> (Self::Answer1, Vec<Extra>) {
+        (self.part_1(input), Vec::new())
+    }
+
+    /// Like `part_1_with_extras`, for part 2
+    fn part_2_with_extras(&self, input: &Self::Input) -> (Self::Answer2, Vec<Extra>) {
+        (self.part_2(input), Vec::new())
+    }
+
+    /// A random input in the day's format, with about `size` of whatever the
+    /// input lists (sensors, jets, ...). `None` for days without a generator.
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// The examples from the puzzle text with their answers, solved with the
+    /// example's constants. Empty for days without an example.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// The simulation that solves `part`, to watch with `aoc animate`.
+    /// `None` for days that aren't simulations on a grid.
+    fn animation(&self, _input: &Self::Input, _part: usize) -> Option<Box<dyn Scene>> {
+        None
+    }
+}
+
+/// A named value reported alongside an answer
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Extra {
+    pub name: &'static str,
+    pub value: String,
+}
+
+impl Extra {
+    pub fn new(name: &'static str, value: impl Display) -> Extra {
+        Extra {
+            name,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// The answer to one part, with how it was found
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report {
+    pub answer: String,
+    /// Wall time spent parsing the input and solving the part
+    pub elapsed: Duration,
+    pub extras: Vec<Extra>,
+}
+
+/// Wall time spent in each stage of solving a day
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct StageTimes {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+impl StageTimes {
+    /// The names of the stages, in the order of `durations`
+    pub const NAMES: [&'static str; 3] = ["parse", "part_1", "part_2"];
+
+    pub fn durations(&self) -> [Duration; 3] {
+        [self.parse, self.part_1, self.part_2]
+    }
+}
+
+/// Object safe view of a `Solution` and its `Params`, with answers rendered
+/// to strings. Implemented for every such day, so that days of different
+/// types can be stored side by side.
+pub trait DynSolution {
+    /// Parse the input and solve the given part, or `None` if there is no such part
+    fn solve_part(&self, input: &str, part: usize) -> Result<Option<String>, ParseError>;
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic>;
+
+    /// Like `solve_part`, but also collect the part's extras, timing the solve
+    /// and the extras together
+    fn report_part(&self, input: &str, part: usize) -> Result<Option<Report>, ParseError>;
+
+    /// Parse the input once and solve both parts, timing each stage
+    fn time_stages(&self, input: &str) -> Result<StageTimes, ParseError>;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+
+    fn examples(&self) -> Vec<Example>;
+
+    /// Parse the input and set up the animation of the given part, if any
+    fn animation(&self, input: &str, part: usize) -> Result<Option<Box<dyn Scene>>, ParseError>;
+
+    fn params(&self) -> Vec<Param>;
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+}
+
+impl<S: Solution + Params> DynSolution for S {
+    fn solve_part(&self, input: &str, part: usize) -> Result<Option<String>, ParseError> {
+        let parsed = self.parse(input)?;
+        Ok(match part {
+            1 => Some(self.part_1(&parsed).to_string()),
+            2 => Some(self.part_2(&parsed).to_string()),
+            _ => None,
+        })
+    }
+
+    fn validate(&self, input: &str) -> Vec<Diagnostic> {
+        Solution::validate(self, input)
+    }
+
+    fn report_part(&self, input: &str, part: usize) -> Result<Option<Report>, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let (answer, extras) = match part {
+            1 => {
+                let (answer, extras) = self.part_1_with_extras(&parsed);
+                (answer.to_string(), extras)
+            }
+            2 => {
+                let (answer, extras) = self.part_2_with_extras(&parsed);
+                (answer.to_string(), extras)
+            }
+            _ => return Ok(None),
+        };
+        let elapsed = start.elapsed();
+
+        Ok(Some(Report {
+            answer,
+            elapsed,
+            extras,
+        }))
+    }
+
+    fn time_stages(&self, input: &str) -> Result<StageTimes, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(black_box(input))?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part_1(&parsed));
+        let part_1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part_2(&parsed));
+        let part_2 = start.elapsed();
+
+        Ok(StageTimes {
+            parse,
+            part_1,
+            part_2,
+        })
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Solution::generate(self, rng, size)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Solution::examples(self)
+    }
+
+    fn animation(&self, input: &str, part: usize) -> Result<Option<Box<dyn Scene>>, ParseError> {
+        Ok(Solution::animation(self, &self.parse(input)?, part))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Params::params(self)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        Params::set_param(self, name, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Sum;
+
+    impl Params for Sum {}
+
+    impl Solution for Sum {
+        type Input = Vec<usize>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(idx, line)| {
+                    line.parse::<usize>()
+                        .map_err(|_| ParseError::at_line(idx + 1, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part_1(&self, input: &Vec<usize>) -> usize {
+            input.iter().sum()
+        }
+
+        fn part_2(&self, input: &Vec<usize>) -> String {
+            format!("{} numbers", input.len())
+        }
+
+        fn part_1_with_extras(&self, input: &Vec<usize>) -> (usize, Vec<Extra>) {
+            let max = input.iter().max().unwrap_or(&0);
+            (self.part_1(input), vec![Extra::new("max", max)])
+        }
+    }
+
+    #[test]
+    fn test_solve_part() {
+        assert_eq!(Sum.solve_part("1\n2\n3", 1), Ok(Some("6".to_string())));
+        assert_eq!(
+            Sum.solve_part("1\n2\n3", 2),
+            Ok(Some("3 numbers".to_string()))
+        );
+        assert_eq!(Sum.solve_part("1\n2\n3", 3), Ok(None));
+        assert_eq!(
+            Sum.solve_part("1\ntwo", 1),
+            Err(ParseError::at_line(2, "expected a number"))
+        );
+    }
+
+    #[test]
+    fn test_report_part() {
+        let report = Sum.report_part("1\n5\n3", 1).unwrap().unwrap();
+        assert_eq!(report.answer, "9");
+        assert_eq!(report.extras, vec![Extra::new("max", 5)]);
+        assert!(Sum
+            .report_part("1\n5\n3", 2)
+            .unwrap()
+            .unwrap()
+            .extras
+            .is_empty());
+        assert_eq!(Sum.report_part("1\n5\n3", 3), Ok(None));
+        assert!(Sum.report_part("1\ntwo", 1).is_err());
+    }
+
+    #[test]
+    fn test_time_stages() {
+        assert!(Sum.time_stages("1\n2\n3").is_ok());
+        assert!(Sum.time_stages("1\ntwo").is_err());
+    }
+}
@@ -0,0 +1,380 @@
+//! Building blocks for checking that an input has the shape a day expects,
+//! without solving it. Problems are reported as diagnostics pointing at the
+//! offending line and column, so that hand edited or generated inputs can be
+//! fixed before they make a parser panic.
+
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::input::is_blank;
+
+/// A problem with the input, at a 1-based line and column
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    /// What should have been there, e.g. `a number`
+    pub expected: String,
+    /// The offending line
+    pub snippet: String,
+}
+
+impl Diagnostic {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        snippet: impl Into<String>,
+    ) -> Diagnostic {
+        Diagnostic {
+            line,
+            column,
+            expected: expected.into(),
+            snippet: snippet.into(),
+        }
+    }
+}
+
+/// The location and expectation, then the snippet with a caret under the column
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(f, "    {:>width$}", "^", width = self.column)
+    }
+}
+
+/// Keeps the line, so that parse errors still point at the problem
+impl From<Diagnostic> for ParseError {
+    fn from(diagnostic: Diagnostic) -> ParseError {
+        ParseError::at_line(
+            diagnostic.line,
+            format!(
+                "column {}: expected {}",
+                diagnostic.column, diagnostic.expected
+            ),
+        )
+    }
+}
+
+/// Fail with the first diagnostic, if any. Parsers that assume the input
+/// has the right shape run the day's checks through this first, so that
+/// bad input is an error rather than a panic.
+pub fn ensure_valid(diagnostics: Vec<Diagnostic>) -> Result<(), ParseError> {
+    match diagnostics.into_iter().next() {
+        Some(diagnostic) => Err(diagnostic.into()),
+        None => Ok(()),
+    }
+}
+
+/// Where a `Scanner` found something other than what it was asked for
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Mismatch {
+    pub column: usize,
+    pub expected: String,
+}
+
+impl Mismatch {
+    pub fn at_line(self, line: usize, snippet: &str) -> Diagnostic {
+        Diagnostic::new(line, self.column, self.expected, snippet)
+    }
+}
+
+/// Walks along a single line, checking it piece by piece
+pub struct Scanner<'a> {
+    line: &'a str,
+    /// Byte offset of the next character
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Scanner<'a> {
+        Scanner { line, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    /// The 1-based column of the next character
+    pub fn column(&self) -> usize {
+        self.line[..self.pos].chars().count() + 1
+    }
+
+    pub fn mismatch(&self, expected: impl Into<String>) -> Mismatch {
+        Mismatch {
+            column: self.column(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.line.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), Mismatch> {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            // point at the first character that differs
+            let n_matching: usize = self
+                .rest()
+                .chars()
+                .zip(literal.chars())
+                .take_while(|(actual, expected)| actual == expected)
+                .map(|(actual, _)| actual.len_utf8())
+                .sum();
+            self.pos += n_matching;
+            Err(self.mismatch(format!("'{}'", &literal[n_matching..])))
+        }
+    }
+
+    /// Consume `literal` if it is next, e.g. for an optional plural `s`
+    pub fn optional(&mut self, literal: &str) -> bool {
+        let matched = self.rest().starts_with(literal);
+        if matched {
+            self.pos += literal.len();
+        }
+        matched
+    }
+
+    /// One or more characters satisfying `pred`, described as `expected`
+    pub fn take_while(
+        &mut self,
+        expected: &str,
+        pred: impl Fn(char) -> bool,
+    ) -> Result<&'a str, Mismatch> {
+        let len: usize = self
+            .rest()
+            .chars()
+            .take_while(|c| pred(*c))
+            .map(char::len_utf8)
+            .sum();
+        if len == 0 {
+            return Err(self.mismatch(expected));
+        }
+        let taken = &self.rest()[..len];
+        self.pos += len;
+        Ok(taken)
+    }
+
+    pub fn unsigned(&mut self) -> Result<u64, Mismatch> {
+        let start = self.column();
+        self.take_while("a number", |c| c.is_ascii_digit())?
+            .parse::<u64>()
+            .map_err(|_| Mismatch {
+                column: start,
+                expected: "a number that fits in 64 bits".to_string(),
+            })
+    }
+
+    /// An integer with an optional leading `-`
+    pub fn integer(&mut self) -> Result<i64, Mismatch> {
+        let start = self.column();
+        let negative = self.optional("-");
+        let digits = self.take_while("an integer", |c| c.is_ascii_digit())?;
+        format!("{}{}", if negative { "-" } else { "" }, digits)
+            .parse::<i64>()
+            .map_err(|_| Mismatch {
+                column: start,
+                expected: "an integer that fits in 64 bits".to_string(),
+            })
+    }
+
+    /// A single character from `chars`
+    pub fn one_of(&mut self, chars: &str) -> Result<char, Mismatch> {
+        match self.peek() {
+            Some(c) if chars.contains(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.mismatch(format!("one of '{}'", chars))),
+        }
+    }
+
+    /// A single character from `chars`, repeated until the end of the line
+    pub fn all_of(&mut self, chars: &str) -> Result<(), Mismatch> {
+        while !self.is_at_end() {
+            self.one_of(chars)?;
+        }
+        Ok(())
+    }
+
+    pub fn end(&mut self) -> Result<(), Mismatch> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.mismatch("the end of the line"))
+        }
+    }
+}
+
+/// Lines of the input with their 1-based line numbers
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(idx, line)| (idx + 1, line))
+}
+
+/// Check every line with `check`, which is given a scanner at the start of the line
+pub fn check_lines(
+    input: &str,
+    check: impl Fn(&mut Scanner) -> Result<(), Mismatch>,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = check_not_empty(input);
+    for (line_no, line) in numbered_lines(input) {
+        if let Err(mismatch) = check(&mut Scanner::new(line)) {
+            diagnostics.push(mismatch.at_line(line_no, line));
+        }
+    }
+    diagnostics
+}
+
+/// A diagnostic if the input holds nothing but whitespace
+pub fn check_not_empty(input: &str) -> Vec<Diagnostic> {
+    if input.trim().is_empty() {
+        vec![Diagnostic::new(1, 1, "some input", "")]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Check that the numbered `lines` form a rectangle of characters from `chars`
+pub fn check_grid(lines: &[(usize, &str)], chars: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let Some((_, first)) = lines.first() else {
+        return diagnostics;
+    };
+    let width = first.chars().count();
+    for (line_no, line) in lines.iter() {
+        let mut scanner = Scanner::new(line);
+        let result = scanner.all_of(chars).and_then(|_| {
+            let line_width = line.chars().count();
+            if line_width == width {
+                Ok(())
+            } else {
+                Err(Mismatch {
+                    column: line_width.min(width) + 1,
+                    expected: format!("a row of {} characters like the first", width),
+                })
+            }
+        });
+        if let Err(mismatch) = result {
+            diagnostics.push(mismatch.at_line(*line_no, line));
+        }
+    }
+    diagnostics
+}
+
+/// The input split at blank lines, each group keeping its line numbers
+pub fn paragraphs(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut groups: Vec<Vec<(usize, &str)>> = vec![Vec::new()];
+    for (line_no, line) in numbered_lines(input) {
+        if is_blank(line) {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push((line_no, line));
+        }
+    }
+    groups.retain(|group| !group.is_empty());
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_sensor(scanner: &mut Scanner) -> Result<(), Mismatch> {
+        scanner.literal("x=")?;
+        scanner.integer()?;
+        scanner.literal(", y=")?;
+        scanner.integer()?;
+        scanner.end()
+    }
+
+    #[test]
+    fn test_scanner() {
+        assert_eq!(check_sensor(&mut Scanner::new("x=-2, y=15")), Ok(()));
+        assert_eq!(
+            check_sensor(&mut Scanner::new("x=-2, z=15")),
+            Err(Mismatch {
+                column: 7,
+                expected: "'y='".to_string()
+            })
+        );
+        assert_eq!(
+            check_sensor(&mut Scanner::new("x=-, y=15"))
+                .unwrap_err()
+                .column,
+            4
+        );
+        assert_eq!(
+            check_sensor(&mut Scanner::new("x=1, y=2!")),
+            Err(Mismatch {
+                column: 9,
+                expected: "the end of the line".to_string()
+            })
+        );
+
+        let mut scanner = Scanner::new("tunnels");
+        scanner.literal("tunnel").unwrap();
+        assert!(scanner.optional("s"));
+        assert!(scanner.is_at_end());
+        assert_eq!(Scanner::new("<>x").all_of("<>").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_check_lines() {
+        let diagnostics = check_lines("x=1, y=2\nx=1 y=2\n", check_sensor);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::new(2, 4, "', y='", "x=1 y=2")]
+        );
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "line 2, column 4: expected ', y='\n    x=1 y=2\n       ^"
+        );
+        assert_eq!(check_lines("\n", check_sensor).len(), 2);
+    }
+
+    #[test]
+    fn test_check_grid() {
+        let input = "#..\n#.#\n.#\n..x";
+        let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+        let diagnostics = check_grid(&lines, "#.");
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::new(3, 3, "a row of 3 characters like the first", ".#"),
+                Diagnostic::new(4, 3, "one of '#.'", "..x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let groups = paragraphs("1\n2\n\n3\n\n\n4\n");
+        assert_eq!(
+            groups,
+            vec![vec![(1, "1"), (2, "2")], vec![(4, "3")], vec![(7, "4")]]
+        );
+    }
+
+    #[test]
+    fn test_ensure_valid() {
+        assert_eq!(ensure_valid(vec![]), Ok(()));
+        let diagnostics = vec![
+            Diagnostic::new(2, 5, "a digit", "x=1,y"),
+            Diagnostic::new(3, 1, "a digit", ""),
+        ];
+        let error = ensure_valid(diagnostics).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: column 5: expected a digit");
+    }
+}